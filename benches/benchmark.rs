@@ -1,39 +1,50 @@
-use adventofcode::days::*;
-use adventofcode::util::load_file;
+use adventofcode::days::{DAYS, SOLVERS};
+use adventofcode::util::read_input;
 use criterion::{criterion_group, criterion_main, Criterion};
+use std::collections::BTreeSet;
 
-#[macro_export]
-macro_rules! make_benchmark {
-    ($c:expr, $dayn:expr, $part:expr) => {
-        let input = load_file($dayn, $part, false, "data");
-        let func = dispatch_function($dayn, $part);
-        $c.bench_function(&format!("day{}::part{}", $dayn, $part), |b| {
-            b.iter(|| func(&input))
-        });
-    };
-}
-
+/// benchmarks the parse step and every implemented part of each day as group `{year}/day{N}`, select
+/// years, days or parts with the usual criterion filter, e.g. `cargo bench -- '2023/day1[0-9]/'` or
+/// `-- part2`
 pub fn criterion_benchmark(c: &mut Criterion) {
-    make_benchmark!(c, 1, 1);
-    make_benchmark!(c, 1, 2);
-    make_benchmark!(c, 2, 1);
-    make_benchmark!(c, 2, 2);
-    make_benchmark!(c, 3, 1);
-    make_benchmark!(c, 3, 2);
-    make_benchmark!(c, 4, 1);
-    make_benchmark!(c, 4, 2);
-    make_benchmark!(c, 5, 1);
-    make_benchmark!(c, 5, 2);
-    make_benchmark!(c, 6, 1);
-    make_benchmark!(c, 6, 2);
-    make_benchmark!(c, 7, 1);
-    make_benchmark!(c, 7, 2);
-    make_benchmark!(c, 8, 1);
-    make_benchmark!(c, 8, 2);
-    make_benchmark!(c, 9, 1);
-    make_benchmark!(c, 9, 2);
-    make_benchmark!(c, 10, 1);
-    make_benchmark!(c, 10, 2);
+    let years: BTreeSet<i32> = SOLVERS.iter().map(|solver| solver.year).collect();
+    for (year, day) in years
+        .into_iter()
+        .flat_map(|year| DAYS.map(move |day| (year, day)))
+    {
+        let solvers: Vec<_> = SOLVERS
+            .iter()
+            .filter(|solver| solver.year == year && solver.day == day && solver.implemented)
+            .collect();
+        if solvers.is_empty() {
+            continue;
+        }
+        let mut group = c.benchmark_group(format!("{year}/day{day}"));
+        for solver in solvers {
+            let input = match read_input(year, day, solver.part, false, "data") {
+                Ok((_, input)) => input,
+                Err(err) => {
+                    eprintln!("skipping {}: {err}", solver.name);
+                    continue;
+                }
+            };
+            let puzzle = match solver.parse(&input) {
+                Ok(puzzle) => puzzle,
+                Err(err) => {
+                    eprintln!("skipping {}: {err}", solver.name);
+                    continue;
+                }
+            };
+            // both parts share the parse step, so it is only benchmarked once per day
+            if solver.part == 1 {
+                group.bench_function("parse", |b| b.iter(|| solver.parse(&input)));
+            }
+            group.bench_function(format!("part{}", solver.part), |b| {
+                b.iter(|| solver.solve_parsed(puzzle.as_ref()))
+            });
+        }
+        group.finish();
+    }
 }
 
 criterion_group!(benches, criterion_benchmark);
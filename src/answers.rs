@@ -0,0 +1,180 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use toml_edit::{value, Item};
+
+use crate::toml_file::{table_entry, FileError, TomlFile};
+use crate::util::Answer;
+
+/// input a solver was run on, the real puzzle input, the short test dataset or one of the named
+/// examples of a day
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Dataset {
+    Real,
+    Test,
+    Example(String),
+}
+impl Dataset {
+    pub fn new(runtest: bool) -> Self {
+        if runtest {
+            Dataset::Test
+        } else {
+            Dataset::Real
+        }
+    }
+}
+impl Display for Dataset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Dataset::Real => f.write_str("real"),
+            Dataset::Test => f.write_str("test"),
+            Dataset::Example(name) => write!(f, "example:{name}"),
+        }
+    }
+}
+impl serde::Serialize for Dataset {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// outcome of comparing a computed answer with the stored one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail(Answer),
+    Missing,
+}
+impl serde::Serialize for Verdict {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => f.write_str("PASS"),
+            Verdict::Fail(_) => f.write_str("FAIL"),
+            Verdict::Missing => f.write_str("MISSING"),
+        }
+    }
+}
+
+/// expected answers stored as toml, keyed by day, part and dataset
+///
+/// ```toml
+/// [day1.part1]
+/// real = 55447
+/// test = 142
+///
+/// [day1.part1.examples]
+/// calibration = 142
+/// ```
+///
+pub struct AnswerFile {
+    file: TomlFile,
+}
+impl AnswerFile {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, FileError> {
+        TomlFile::load(path).map(|file| Self { file })
+    }
+    pub fn path(&self) -> &Path {
+        self.file.path()
+    }
+    pub fn get(&self, day: i32, part: i32, dataset: &Dataset) -> Option<Answer> {
+        let part = self
+            .file
+            .document()
+            .get(&format!("day{day}"))?
+            .get(format!("part{part}"))?;
+        let item = match dataset {
+            Dataset::Example(name) => part.get("examples")?.get(name)?,
+            dataset => part.get(dataset.to_string())?,
+        };
+        match item.as_integer() {
+            Some(number) => Some(Answer::Integer(number)),
+            None => item.as_str()?.parse().ok(),
+        }
+    }
+    /// whether any part of `day` has an expected answer for the example `name`
+    pub fn has_example(&self, day: i32, name: &str) -> bool {
+        self.file
+            .document()
+            .get(&format!("day{day}"))
+            .and_then(Item::as_table_like)
+            .is_some_and(|parts| {
+                parts
+                    .iter()
+                    .any(|(_, part)| part.get("examples").and_then(|e| e.get(name)).is_some())
+            })
+    }
+    pub fn verify(&self, day: i32, part: i32, dataset: &Dataset, answer: &Answer) -> Verdict {
+        match self.get(day, part, dataset) {
+            Some(expected) if &expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected),
+            None => Verdict::Missing,
+        }
+    }
+    /// stores `answer`, replacing a previously stored one
+    pub fn record(&mut self, day: i32, part: i32, dataset: &Dataset, answer: &Answer) {
+        let day = table_entry(self.file.document_mut(), &format!("day{day}"));
+        // the day table only groups the parts, so it does not need a header of its own
+        day.set_implicit(true);
+        let part = table_entry(day, &format!("part{part}"));
+        // same for a part holding nothing but examples
+        part.set_implicit(true);
+        let (table, key) = match dataset {
+            Dataset::Example(name) => (table_entry(part, "examples"), name.clone()),
+            dataset => (part, dataset.to_string()),
+        };
+        table[&key] = match answer {
+            Answer::Integer(number) => value(*number),
+            answer => value(answer.to_string()),
+        };
+    }
+    pub fn save(&self) -> Result<(), FileError> {
+        self.file.save()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_record_and_verify() {
+        let mut answers = AnswerFile {
+            file: TomlFile::default(),
+        };
+        answers.record(1, 1, &Dataset::Real, &Answer::from(55447));
+        assert_eq!(
+            answers.verify(1, 1, &Dataset::Real, &Answer::from(55447)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.verify(1, 1, &Dataset::Real, &Answer::from(1)),
+            Verdict::Fail(Answer::from(55447))
+        );
+        assert_eq!(
+            answers.verify(1, 1, &Dataset::Test, &Answer::from(142)),
+            Verdict::Missing
+        );
+        answers.record(1, 1, &Dataset::Test, &Answer::from(142));
+        answers.record(25, 2, &Dataset::Real, &Answer::from("done!"));
+        assert_eq!(
+            answers.file.document().to_string(),
+            "[day1.part1]\nreal = 55447\ntest = 142\n\n[day25.part2]\nreal = \"done!\"\n"
+        );
+        assert_eq!(
+            answers.get(25, 2, &Dataset::Real),
+            Some(Answer::from("done!"))
+        );
+        let example = Dataset::Example("larger".to_owned());
+        assert!(!answers.has_example(1, "larger"));
+        answers.record(1, 2, &example, &Answer::from(281));
+        assert!(answers.has_example(1, "larger"));
+        assert_eq!(answers.get(1, 2, &example), Some(Answer::from(281)));
+        let document = answers.file.document().to_string();
+        assert!(document.contains("\n[day1.part2.examples]\nlarger = 281\n"));
+        assert!(!document.contains("[day1.part2]"));
+    }
+}
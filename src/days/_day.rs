@@ -1,2 +1,32 @@
-pub fn part1(input: &str) {}
-pub fn part2(input: &str) {}
+use crate::util::{ParseError, SolveError};
+
+pub struct Puzzle {}
+
+pub fn parse(_input: &str) -> Result<Puzzle, ParseError> {
+    Ok(Puzzle {})
+}
+
+pub fn part1(_puzzle: &Puzzle) -> Result<i64, SolveError> {
+    todo!()
+}
+pub fn part2(_puzzle: &Puzzle) -> Result<i64, SolveError> {
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const EXAMPLE: &str = "";
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn test_part1() {
+        let puzzle = parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&puzzle), Ok(0));
+    }
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn test_part2() {
+        let puzzle = parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&puzzle), Ok(0));
+    }
+}
@@ -1,81 +1,313 @@
-pub mod day1;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day2;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
+pub mod year2023;
 
-pub fn dispatch_function(day: i32, part: i32) -> fn(&str) -> i64 {
-    match (day, part) {
-        (1, 1) => day1::part1,
-        (1, 2) => day1::part2,
-        (2, 1) => day2::part1,
-        (2, 2) => day2::part2,
-        (3, 1) => day3::part1,
-        (3, 2) => day3::part2,
-        (4, 1) => day4::part1,
-        (4, 2) => day4::part2,
-        (5, 1) => day5::part1,
-        (5, 2) => day5::part2,
-        (6, 1) => day6::part1,
-        (6, 2) => day6::part2,
-        (7, 1) => day7::part1,
-        (7, 2) => day7::part2,
-        (8, 1) => day8::part1,
-        (8, 2) => day8::part2,
-        (9, 1) => day9::part1,
-        (9, 2) => day9::part2,
-        (10, 1) => day10::part1,
-        (10, 2) => day10::part2,
-        (11, 1) => day11::part1,
-        (11, 2) => day11::part2,
-        (12, 1) => day12::part1,
-        (12, 2) => day12::part2,
-        (13, 1) => day13::part1,
-        (13, 2) => day13::part2,
-        (14, 1) => day14::part1,
-        (14, 2) => day14::part2,
-        (15, 1) => day15::part1,
-        (15, 2) => day15::part2,
-        (16, 1) => day16::part1,
-        (16, 2) => day16::part2,
-        (17, 1) => day17::part1,
-        (17, 2) => day17::part2,
-        (18, 1) => day18::part1,
-        (18, 2) => day18::part2,
-        (19, 1) => day19::part1,
-        (19, 2) => day19::part2,
-        (20, 1) => day20::part1,
-        (20, 2) => day20::part2,
-        (21, 1) => day21::part1,
-        (21, 2) => day21::part2,
-        (22, 1) => day22::part1,
-        (22, 2) => day22::part2,
-        (23, 1) => day23::part1,
-        (23, 2) => day23::part2,
-        (24, 1) => day24::part1,
-        (24, 2) => day24::part2,
-        (25, 1) => day25::part1,
-        (25, 2) => day25::part2,
-        _ => |_| 0,
+use std::any::Any;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+use crate::util::{Answer, ParseError, SolveError};
+
+/// all days of an advent calendar
+pub const DAYS: RangeInclusive<i32> = 1..=25;
+
+/// puzzle of a day as returned by its `parse` function, shared by both parts
+pub type Puzzle = Box<dyn Any + Send>;
+
+/// describes a single part of a day, the function parsing the input of the day and the function
+/// solving the part on the parsed puzzle
+#[derive(Debug, Clone, Copy)]
+pub struct Solver {
+    pub year: i32,
+    pub day: i32,
+    pub part: i32,
+    pub name: &'static str,
+    pub parser: fn(&str) -> Result<Puzzle, ParseError>,
+    pub function: fn(&dyn Any) -> Result<Answer, SolveError>,
+    pub implemented: bool,
+}
+impl Solver {
+    /// parses the input of the day, locating a potential parse error within `input`
+    pub fn parse(&self, input: &str) -> Result<Puzzle, ParseError> {
+        (self.parser)(input).map_err(|err| err.locate(input))
+    }
+    /// solves the part on a puzzle parsed by a solver of the same day
+    pub fn solve_parsed(&self, puzzle: &dyn Any) -> Result<Answer, SolveError> {
+        (self.function)(puzzle)
+    }
+    /// parses `input` and solves the part
+    pub fn solve(&self, input: &str) -> Result<Answer, SolveError> {
+        let puzzle = self.parse(input)?;
+        self.solve_parsed(puzzle.as_ref())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SolverError {
+    UnknownYear(i32),
+    UnknownDay(i32),
+    UnknownPart(i32, i32),
+    NotImplemented(i32, i32),
+    /// a range of days without any day, like `12..5`
+    EmptyDays(i32, i32),
+}
+impl Display for SolverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolverError::UnknownYear(year) => write!(f, "no solvers for year {year}"),
+            SolverError::UnknownDay(day) => write!(f, "unknown day {day}"),
+            SolverError::UnknownPart(day, part) => write!(f, "day {day} has no part {part}"),
+            SolverError::NotImplemented(day, part) => {
+                write!(f, "day {day} part {part} is not implemented")
+            }
+            SolverError::EmptyDays(start, stop) => {
+                write!(f, "the range of days {start}..={stop} is empty")
+            }
+        }
+    }
+}
+impl std::error::Error for SolverError {}
+
+macro_rules! solver {
+    ($year:literal, $day:literal, $part:literal, $season:ident::$module:ident::$function:ident) => {
+        solver!($year, $day, $part, $season::$module::$function, implemented: true)
+    };
+    ($year:literal, $day:literal, $part:literal, $season:ident::$module:ident::$function:ident, implemented: $implemented:literal) => {
+        Solver {
+            year: $year,
+            day: $day,
+            part: $part,
+            name: concat!(stringify!($module), "::", stringify!($function)),
+            parser: |input| $season::$module::parse(input).map(|puzzle| Box::new(puzzle) as Puzzle),
+            // the puzzle type is inferred from the part, solvers may return anything convertible
+            // into an answer
+            function: |puzzle| {
+                let puzzle = puzzle
+                    .downcast_ref()
+                    .expect(concat!("puzzle not parsed by ", stringify!($module)));
+                $season::$module::$function(puzzle).map(Answer::from)
+            },
+            implemented: $implemented,
+        }
+    };
+}
+
+/// registry of all solvers, ordered by year, day and part
+pub const SOLVERS: &[Solver] = &[
+    solver!(2023, 1, 1, year2023::day1::part1),
+    solver!(2023, 1, 2, year2023::day1::part2),
+    solver!(2023, 2, 1, year2023::day2::part1),
+    solver!(2023, 2, 2, year2023::day2::part2),
+    solver!(2023, 3, 1, year2023::day3::part1),
+    solver!(2023, 3, 2, year2023::day3::part2),
+    solver!(2023, 4, 1, year2023::day4::part1),
+    solver!(2023, 4, 2, year2023::day4::part2),
+    solver!(2023, 5, 1, year2023::day5::part1),
+    solver!(2023, 5, 2, year2023::day5::part2),
+    solver!(2023, 6, 1, year2023::day6::part1),
+    solver!(2023, 6, 2, year2023::day6::part2),
+    solver!(2023, 7, 1, year2023::day7::part1),
+    solver!(2023, 7, 2, year2023::day7::part2),
+    solver!(2023, 8, 1, year2023::day8::part1),
+    solver!(2023, 8, 2, year2023::day8::part2),
+    solver!(2023, 9, 1, year2023::day9::part1),
+    solver!(2023, 9, 2, year2023::day9::part2),
+    solver!(2023, 10, 1, year2023::day10::part1),
+    solver!(2023, 10, 2, year2023::day10::part2),
+    solver!(2023, 11, 1, year2023::day11::part1),
+    solver!(2023, 11, 2, year2023::day11::part2),
+    solver!(2023, 12, 1, year2023::day12::part1),
+    solver!(2023, 12, 2, year2023::day12::part2),
+    solver!(2023, 13, 1, year2023::day13::part1),
+    solver!(2023, 13, 2, year2023::day13::part2),
+    solver!(2023, 14, 1, year2023::day14::part1),
+    solver!(2023, 14, 2, year2023::day14::part2),
+    solver!(2023, 15, 1, year2023::day15::part1),
+    solver!(2023, 15, 2, year2023::day15::part2),
+    solver!(2023, 16, 1, year2023::day16::part1),
+    solver!(2023, 16, 2, year2023::day16::part2),
+    solver!(2023, 17, 1, year2023::day17::part1),
+    solver!(2023, 17, 2, year2023::day17::part2),
+    solver!(2023, 18, 1, year2023::day18::part1),
+    solver!(2023, 18, 2, year2023::day18::part2),
+    solver!(2023, 19, 1, year2023::day19::part1),
+    solver!(2023, 19, 2, year2023::day19::part2),
+    solver!(2023, 20, 1, year2023::day20::part1),
+    solver!(2023, 20, 2, year2023::day20::part2),
+    solver!(2023, 21, 1, year2023::day21::part1),
+    solver!(2023, 21, 2, year2023::day21::part2),
+    solver!(2023, 22, 1, year2023::day22::part1),
+    solver!(2023, 22, 2, year2023::day22::part2),
+    solver!(2023, 23, 1, year2023::day23::part1),
+    solver!(2023, 23, 2, year2023::day23::part2),
+    solver!(2023, 24, 1, year2023::day24::part1),
+    solver!(2023, 24, 2, year2023::day24::part2),
+    solver!(2023, 25, 1, year2023::day25::part1),
+    solver!(2023, 25, 2, year2023::day25::part2, implemented: false),
+];
+
+/// most recent year with at least one implemented solver, the default of the runner
+///
+/// a season that was only scaffolded does not count until one of its parts is implemented, without
+/// any implemented solver the most recent registered year is used
+pub fn latest_year() -> i32 {
+    let latest = |only_implemented: bool| {
+        SOLVERS
+            .iter()
+            .filter(|solver| solver.implemented || !only_implemented)
+            .map(|solver| solver.year)
+            .max()
+    };
+    latest(true)
+        .or_else(|| latest(false))
+        .expect("the registry is not empty")
+}
+
+/// looks up the solver for the given year, day and part
+pub fn find_solver(year: i32, day: i32, part: i32) -> Result<&'static Solver, SolverError> {
+    let mut solvers = SOLVERS
+        .iter()
+        .filter(|solver| solver.year == year)
+        .peekable();
+    if solvers.peek().is_none() {
+        return Err(SolverError::UnknownYear(year));
+    }
+    let mut solvers = solvers.filter(|solver| solver.day == day).peekable();
+    if solvers.peek().is_none() {
+        return Err(SolverError::UnknownDay(day));
+    }
+    let solver = solvers
+        .find(|solver| solver.part == part)
+        .ok_or(SolverError::UnknownPart(day, part))?;
+    if solver.implemented {
+        Ok(solver)
+    } else {
+        Err(SolverError::NotImplemented(day, part))
+    }
+}
+
+/// checks that a range of days and an optional part only select registered solvers of the year
+pub fn check_selection(
+    year: i32,
+    days: &RangeInclusive<i32>,
+    part: Option<i32>,
+) -> Result<(), SolverError> {
+    let solvers: Vec<&Solver> = SOLVERS
+        .iter()
+        .filter(|solver| solver.year == year)
+        .collect();
+    if solvers.is_empty() {
+        return Err(SolverError::UnknownYear(year));
+    }
+    if days.is_empty() {
+        return Err(SolverError::EmptyDays(*days.start(), *days.end()));
+    }
+    if let Some(day) = [*days.start(), *days.end()]
+        .into_iter()
+        .find(|day| !DAYS.contains(day))
+    {
+        return Err(SolverError::UnknownDay(day));
+    }
+    if let Some(part) = part {
+        if !solvers
+            .iter()
+            .any(|solver| days.contains(&solver.day) && solver.part == part)
+        {
+            return Err(SolverError::UnknownPart(*days.start(), part));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_registry_covers_all_days() {
+        let registered: Vec<(i32, i32)> = SOLVERS
+            .iter()
+            .filter(|solver| solver.year == 2023)
+            .map(|solver| (solver.day, solver.part))
+            .collect();
+        let expected: Vec<(i32, i32)> = DAYS.flat_map(|day| [(day, 1), (day, 2)]).collect();
+        assert_eq!(registered, expected);
+    }
+    #[test]
+    fn test_find_solver() {
+        assert_eq!(find_solver(2023, 7, 2).unwrap().name, "day7::part2");
+        assert_eq!(
+            find_solver(2023, 26, 1).unwrap_err(),
+            SolverError::UnknownDay(26)
+        );
+        assert_eq!(
+            find_solver(2023, 1, 3).unwrap_err(),
+            SolverError::UnknownPart(1, 3)
+        );
+        assert_eq!(
+            find_solver(2023, 25, 2).unwrap_err(),
+            SolverError::NotImplemented(25, 2)
+        );
+        assert_eq!(
+            find_solver(2015, 1, 1).unwrap_err(),
+            SolverError::UnknownYear(2015)
+        );
+        assert!(SOLVERS
+            .iter()
+            .filter(|solver| solver.implemented)
+            .all(|solver| solver.year <= latest_year()));
+        assert!(SOLVERS
+            .iter()
+            .any(|solver| solver.implemented && solver.year == latest_year()));
+    }
+    #[test]
+    fn test_check_selection() {
+        assert_eq!(check_selection(2023, &(1..=25), None), Ok(()));
+        assert_eq!(check_selection(2023, &(5..=12), Some(2)), Ok(()));
+        assert_eq!(
+            check_selection(2015, &(1..=25), None),
+            Err(SolverError::UnknownYear(2015))
+        );
+        assert_eq!(
+            check_selection(2023, &(30..=30), None),
+            Err(SolverError::UnknownDay(30))
+        );
+        assert_eq!(
+            check_selection(2023, &(20..=26), None),
+            Err(SolverError::UnknownDay(26))
+        );
+        assert_eq!(
+            check_selection(2023, &RangeInclusive::new(12, 4), None),
+            Err(SolverError::EmptyDays(12, 4))
+        );
+        assert_eq!(
+            check_selection(2023, &(1..=3), Some(3)),
+            Err(SolverError::UnknownPart(1, 3))
+        );
+    }
+    #[test]
+    fn test_solve_errors() {
+        let err = find_solver(2023, 1, 1)
+            .unwrap()
+            .solve("ab1\nxyz\n")
+            .unwrap_err();
+        assert_eq!(
+            err,
+            SolveError::Input(ParseError::on_line(2, "line contains no digit"))
+        );
+        assert_eq!(
+            find_solver(2023, 17, 2).unwrap().solve("19\n"),
+            Err(SolveError::unsolvable(
+                "the bottom right block can not be reached"
+            ))
+        );
+    }
+    #[test]
+    fn test_shared_puzzle() {
+        let input = "two1nine\neightwothree\n";
+        let puzzle = find_solver(2023, 1, 1).unwrap().parse(input).unwrap();
+        assert_eq!(
+            find_solver(2023, 1, 2)
+                .unwrap()
+                .solve_parsed(puzzle.as_ref()),
+            Ok(Answer::from(29 + 83))
+        );
     }
 }
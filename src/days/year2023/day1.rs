@@ -0,0 +1,79 @@
+use crate::util::{ParseError, SolveError};
+
+/// calibration document, the lines are only interpreted by the parts since part 2 also accepts
+/// spelled out digits
+pub struct Puzzle {
+    lines: Vec<String>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    Ok(Puzzle {
+        lines: input.lines().map(str::to_owned).collect(),
+    })
+}
+
+fn parse_line(line: &str) -> Option<u64> {
+    let first = line.chars().find(|c| c.is_ascii_digit())?;
+    let last = line.chars().rev().find(|c| c.is_ascii_digit())?;
+
+    (first.to_string() + &last.to_string()).parse::<u64>().ok()
+}
+
+const REPLACEMENTS: [(&str, char); 9] = [
+    ("one", '1'),
+    ("two", '2'),
+    ("three", '3'),
+    ("four", '4'),
+    ("five", '5'),
+    ("six", '6'),
+    ("seven", '7'),
+    ("eight", '8'),
+    ("nine", '9'),
+];
+
+fn matches_patterns(line: &str, start: usize, patterns: (&str, char)) -> Option<char> {
+    let startline = line[start..].to_owned();
+    let (text, number) = patterns;
+    if startline.starts_with(text) || startline.starts_with(number) {
+        Some(number)
+    } else {
+        None
+    }
+}
+
+fn convert_ascii(line: &str) -> String {
+    let mut linecvt = "".to_owned();
+
+    for start in 0..line.len() {
+        for pattern in REPLACEMENTS.iter() {
+            if let Some(number) = matches_patterns(line, start, *pattern) {
+                linecvt += &number.to_string();
+            }
+        }
+    }
+    linecvt
+}
+
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    // for each line find first and last digit
+    let mut sum = 0;
+    for (idx, line) in puzzle.lines.iter().enumerate() {
+        let num = parse_line(line)
+            .ok_or_else(|| ParseError::on_line(idx + 1, "line contains no digit"))?;
+        // num);
+        sum += num;
+    }
+    Ok(sum as i64)
+}
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    // for each line find first and last digit
+    let mut sum = 0;
+
+    for (idx, line) in puzzle.lines.iter().enumerate() {
+        let linecvt = convert_ascii(line);
+        let num = parse_line(linecvt.as_str())
+            .ok_or_else(|| ParseError::on_line(idx + 1, "line contains no digit"))?;
+        sum += num;
+    }
+    Ok(sum as i64)
+}
@@ -1,7 +1,9 @@
 use std::{collections::HashSet, fmt::Debug, ops::Index};
 
+use crate::util::{Location, ParseError, SolveError};
+
 #[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
-enum PipeKind {
+pub enum PipeKind {
     NS,
     EW,
     NE,
@@ -53,17 +55,17 @@ impl Direction {
 }
 
 impl PipeKind {
-    fn from_char(char: char) -> Self {
+    fn from_char(char: char) -> Option<Self> {
         match char {
-            '|' => Self::NS,
-            '-' => Self::EW,
-            'L' => Self::NE,
-            'J' => Self::NW,
-            '7' => Self::SW,
-            'F' => Self::SE,
-            '.' => Self::G,
-            'S' => Self::S,
-            _ => panic!("should not get here!"),
+            '|' => Some(Self::NS),
+            '-' => Some(Self::EW),
+            'L' => Some(Self::NE),
+            'J' => Some(Self::NW),
+            '7' => Some(Self::SW),
+            'F' => Some(Self::SE),
+            '.' => Some(Self::G),
+            'S' => Some(Self::S),
+            _ => None,
         }
     }
     fn to_char(self) -> char {
@@ -95,23 +97,23 @@ impl PipeKind {
             ],
         }
     }
-    fn from_directions(directions: &[Direction; 2]) -> Self {
+    fn from_directions(directions: &[Direction; 2]) -> Option<Self> {
         let mut directions = *directions;
         directions.sort();
         match directions {
-            [Direction::North, Direction::South] => PipeKind::NS,
-            [Direction::East, Direction::West] => PipeKind::EW,
-            [Direction::North, Direction::East] => PipeKind::NE,
-            [Direction::North, Direction::West] => PipeKind::NW,
-            [Direction::South, Direction::West] => PipeKind::SW,
-            [Direction::East, Direction::South] => PipeKind::SE,
-            _ => panic!(),
+            [Direction::North, Direction::South] => Some(PipeKind::NS),
+            [Direction::East, Direction::West] => Some(PipeKind::EW),
+            [Direction::North, Direction::East] => Some(PipeKind::NE),
+            [Direction::North, Direction::West] => Some(PipeKind::NW),
+            [Direction::South, Direction::West] => Some(PipeKind::SW),
+            [Direction::East, Direction::South] => Some(PipeKind::SE),
+            _ => None,
         }
     }
 }
 
 type Idx = (usize, usize);
-struct Map {
+pub struct Map {
     data: Vec<Vec<PipeKind>>,
     shape: Idx,
 }
@@ -125,18 +127,29 @@ impl Index<Idx> for Map {
 }
 
 impl Map {
-    fn from_lines(input: &str) -> Self {
+    fn from_lines(input: &str) -> Result<Self, ParseError> {
         let data: Vec<Vec<PipeKind>> = input
             .lines()
             .map(|line| {
-                line.chars()
-                    .map(PipeKind::from_char)
-                    .collect::<Vec<PipeKind>>()
+                line.char_indices()
+                    .map(|(idx, char)| {
+                        PipeKind::from_char(char).ok_or_else(|| {
+                            ParseError::at(&line[idx..], format!("invalid pipe '{char}'"))
+                        })
+                    })
+                    .collect::<Result<Vec<PipeKind>, ParseError>>()
             })
-            .collect();
-        let shape = (data.len(), data.first().unwrap().len());
+            .collect::<Result<_, _>>()?;
+        let width = data
+            .first()
+            .ok_or_else(|| ParseError::at(input, "empty map"))?
+            .len();
+        if let Some((line, _)) = input.lines().zip(&data).find(|(_, row)| row.len() != width) {
+            return Err(ParseError::at(line, format!("expected {width} columns")));
+        }
+        let shape = (data.len(), width);
 
-        Self { data, shape }
+        Ok(Self { data, shape })
     }
 
     fn distance_map(&self) -> Vec<Vec<i64>> {
@@ -243,6 +256,16 @@ impl Map {
     }
 }
 
+/// error pointing at the tile `idx` of the input
+fn error_at(idx: Idx, message: &str) -> ParseError {
+    let mut err = ParseError::new(message);
+    err.location = Some(Location {
+        line: idx.0 + 1,
+        column: idx.1 + 1,
+    });
+    err
+}
+
 fn write_dist(idx: Idx, dist: i64, map: &mut [Vec<i64>]) -> bool {
     let val = map[idx.0][idx.1];
     if val > dist || val == -1 {
@@ -253,10 +276,17 @@ fn write_dist(idx: Idx, dist: i64, map: &mut [Vec<i64>]) -> bool {
     }
 }
 
-pub fn part1(input: &str) -> i64 {
-    let map = Map::from_lines(input);
+/// the pipe map
+pub type Puzzle = Map;
 
-    let start = map.find_start().unwrap();
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    Map::from_lines(input)
+}
+
+pub fn part1(map: &Puzzle) -> Result<i64, SolveError> {
+    let start = map
+        .find_start()
+        .ok_or_else(|| ParseError::new("missing start 'S'"))?;
 
     let mut pipes = map.find_connecting_pipes(start);
 
@@ -276,55 +306,50 @@ pub fn part1(input: &str) -> i64 {
             .collect();
         distance += 1;
     }
-    // dist_map);
-    let solution = dist_map.iter().flatten().max().unwrap();
-    *solution
+    let solution = dist_map
+        .iter()
+        .flatten()
+        .max()
+        .filter(|distance| **distance > 0)
+        .ok_or_else(|| error_at(start, "start is not connected to any pipe"))?;
+    Ok(*solution)
 }
 
-fn get_rot(olddir: &Direction, dir: &Direction) -> i64 {
-    match olddir {
-        Direction::North => match dir {
-            Direction::North => 0,
-            Direction::East => 1,
-            Direction::West => -1,
-            _ => panic!("wrong direction"),
-        },
-        Direction::East => match dir {
-            Direction::East => 0,
-            Direction::North => -1,
-            Direction::South => 1,
-            _ => panic!("wrong direction"),
-        },
-        Direction::South => match dir {
-            Direction::South => 0,
-            Direction::West => 1,
-            Direction::East => -1,
-            _ => panic!("wrong direction"),
-        },
-        Direction::West => match dir {
-            Direction::West => 0,
-            Direction::North => 1,
-            Direction::South => -1,
-            _ => panic!("wrong direction"),
-        },
+/// quarter turns from `olddir` to `dir`, clockwise is positive, none if `dir` turns back
+fn get_rot(olddir: &Direction, dir: &Direction) -> Option<i64> {
+    if dir == olddir {
+        Some(0)
+    } else if dir == &olddir.right() {
+        Some(1)
+    } else if dir.right() == *olddir {
+        Some(-1)
+    } else {
+        None
     }
 }
 
-pub fn part2(input: &str) -> i64 {
-    let map = Map::from_lines(input);
-
-    let start = map.find_start().unwrap();
+pub fn part2(map: &Puzzle) -> Result<i64, SolveError> {
+    let start = map
+        .find_start()
+        .ok_or_else(|| ParseError::new("missing start 'S'"))?;
 
     let pipes = map.find_connecting_pipes(start);
-    assert!(pipes.len() == 2);
+    if pipes.len() != 2 {
+        return Err(error_at(start, "start must connect to exactly two pipes").into());
+    }
     let mut start_dirs = [pipes[0].1, pipes[1].1];
     start_dirs.sort();
-    let start_kind = PipeKind::from_directions(&start_dirs);
+    let start_kind = PipeKind::from_directions(&start_dirs)
+        .ok_or_else(|| error_at(start, "start must connect to two different sides"))?;
 
     let pipidx = 0;
     let (mut pipe, mut dir) = pipes[pipidx];
 
-    let mut loop_map: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let mut loop_map: Vec<Vec<char>> = map
+        .data
+        .iter()
+        .map(|row| row.iter().map(|kind| kind.to_char()).collect())
+        .collect();
 
     loop_map[start.0][start.1] = start_kind.to_char();
 
@@ -334,8 +359,11 @@ pub fn part2(input: &str) -> i64 {
     while pipe.idx != start {
         loop_map[pipe.idx.0][pipe.idx.1] = 'x';
         let olddir = dir;
-        (pipe, dir) = map.next_pipe(&pipe, &dir).unwrap();
-        rot += get_rot(&olddir, &dir);
+        (pipe, dir) = map
+            .next_pipe(&pipe, &dir)
+            .ok_or_else(|| error_at(pipe.idx, "pipe loop is not closed"))?;
+        rot += get_rot(&olddir, &dir)
+            .ok_or_else(|| error_at(pipe.idx, "pipe loop turns back on itself"))?;
     }
 
     // make sure we run clockwise by using the different starting dir if rot < 0
@@ -354,7 +382,9 @@ pub fn part2(input: &str) -> i64 {
                 insides.insert(idx);
             }
         }
-        (pipe, dir) = map.next_pipe(&pipe, &dir).unwrap();
+        (pipe, dir) = map
+            .next_pipe(&pipe, &dir)
+            .ok_or_else(|| error_at(pipe.idx, "pipe loop is not closed"))?;
     }
 
     let mut active = insides.clone();
@@ -377,5 +407,5 @@ pub fn part2(input: &str) -> i64 {
     }
 
     let solution = insides.len();
-    solution as i64
+    Ok(solution as i64)
 }
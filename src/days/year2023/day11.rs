@@ -0,0 +1,79 @@
+use crate::util::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Space {
+    Empty,
+    Galaxy,
+}
+impl FromChar for Space {
+    fn default() -> Self {
+        Self::Empty
+    }
+    fn from_char(char: &char) -> Option<Self> {
+        match char {
+            '.' => Some(Self::Empty),
+            '#' => Some(Self::Galaxy),
+            _ => None,
+        }
+    }
+}
+impl MatrixElement for Space {}
+impl ToChar for Space {
+    fn to_char(&self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::Galaxy => '#',
+        }
+    }
+}
+
+/// positions of the galaxies once every empty row and column has grown `factor` times as large
+fn expanded_galaxies(image: &Matrix<Space>, factor: i64) -> Vec<MatrixIdx> {
+    let is_empty = |space: &Space| space == &Space::Empty;
+    let empty_rows: Vec<i64> = (0..image.height())
+        .filter(|&row| image.row(row).is_some_and(|row| row.iter().all(is_empty)))
+        .collect();
+    let empty_cols: Vec<i64> = (0..image.width())
+        .filter(|&col| image.col(col).is_some_and(|mut col| col.all(is_empty)))
+        .collect();
+
+    // every empty row and column before a galaxy moves it by factor - 1
+    image
+        .findall(|space| space == &Space::Galaxy)
+        .into_iter()
+        .map(|galaxy| {
+            let rows = empty_rows.iter().filter(|&row| row < &galaxy.row).count() as i64;
+            let cols = empty_cols.iter().filter(|&col| col < &galaxy.col).count() as i64;
+            MatrixIdx {
+                row: galaxy.row + rows * (factor - 1),
+                col: galaxy.col + cols * (factor - 1),
+            }
+        })
+        .collect()
+}
+fn dist(a: &MatrixIdx, b: &MatrixIdx) -> i64 {
+    (a.row - b.row).abs() + (a.col - b.col).abs()
+}
+fn sum_of_distances(galaxies: &[MatrixIdx]) -> i64 {
+    let mut solution = 0;
+    for (idx, a) in galaxies.iter().enumerate() {
+        for b in galaxies.iter().skip(idx + 1) {
+            solution += dist(a, b);
+        }
+    }
+    solution
+}
+
+/// the image of the galaxies
+pub type Puzzle = Matrix<Space>;
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    Matrix::from_string(input)
+}
+
+pub fn part1(image: &Puzzle) -> Result<i64, SolveError> {
+    Ok(sum_of_distances(&expanded_galaxies(image, 2)))
+}
+pub fn part2(image: &Puzzle) -> Result<i64, SolveError> {
+    Ok(sum_of_distances(&expanded_galaxies(image, 1000000)))
+}
@@ -1,38 +1,48 @@
-use std::{collections::HashMap, usize};
+use std::collections::HashMap;
 
-fn parse_input(input: &str) -> (Vec<Vec<u8>>, Vec<Vec<usize>>) {
+use crate::util::{parse_num, split_once, ParseError, SolveError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+/// the condition records and the group sizes of the damaged springs
+pub type Puzzle = (Vec<Vec<Spring>>, Vec<Vec<usize>>);
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
     let mut inputs = Vec::new();
     let mut springs = Vec::new();
     for line in input.lines() {
-        let tosolve: Vec<u8> = line
-            .split(' ')
-            .next()
-            .unwrap()
-            .chars()
-            .map(|c| match c {
-                '.' => 0,
-                '#' => 1,
-                '?' => 2,
-                _ => panic!("should not happen"),
+        let (record, groups) = split_once(line, " ")?;
+        let tosolve: Vec<Spring> = record
+            .char_indices()
+            .map(|(idx, c)| match c {
+                '.' => Ok(Spring::Operational),
+                '#' => Ok(Spring::Damaged),
+                '?' => Ok(Spring::Unknown),
+                _ => Err(ParseError::at(
+                    &record[idx..],
+                    format!("invalid spring '{c}'"),
+                )),
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
         inputs.push(tosolve);
 
-        let spring: Vec<usize> = line
-            .split(' ')
-            .nth(1)
-            .unwrap()
+        let spring: Vec<usize> = groups
             .split(',')
-            .map(|str| str.parse::<usize>().unwrap())
-            .collect();
+            .map(parse_num::<usize>)
+            .collect::<Result<_, _>>()?;
         springs.push(spring);
         // tosolve.chars().filter(|c| c == &'?').count();
     }
-    (inputs, springs)
+    Ok((inputs, springs))
 }
 
-pub fn part1(input: &str) -> i64 {
-    let (inputs, springs) = parse_input(input);
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let (inputs, springs) = puzzle;
     let mut solution = 0;
     for (input, spring) in inputs.iter().zip(springs) {
         let input = input.clone();
@@ -40,19 +50,19 @@ pub fn part1(input: &str) -> i64 {
         let mut map = Memo {
             map: HashMap::new(),
         };
-        let solution_count = map.arrangment(0, 0, false, &mut groups, &input, &spring);
+        let solution_count = map.arrangment(0, 0, false, &mut groups, &input, spring);
         solution += solution_count;
     }
 
-    solution
+    Ok(solution)
 }
-pub fn part2(input: &str) -> i64 {
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
     let mut solution = 0;
-    let (inputs, springs) = parse_input(input);
+    let (inputs, springs) = puzzle;
     for (input, spring) in inputs.iter().zip(springs) {
         let mut input_tot = input.clone();
         let mut input = input.clone();
-        input.insert(0, 2);
+        input.insert(0, Spring::Unknown);
         let mut spring_tot = spring.clone();
 
         for _ in 0..4 {
@@ -73,22 +83,14 @@ pub fn part2(input: &str) -> i64 {
         let solution_count = map.arrangment(0, 0, false, &mut groups, &input_tot, &spring_tot);
         solution += solution_count;
     }
-    solution
+    Ok(solution)
 }
 
-fn could_be_spring(c: &u8) -> bool {
-    match c {
-        2 | 1 => true,
-        0 => false,
-        _ => panic!(),
-    }
+fn could_be_spring(c: &Spring) -> bool {
+    c != &Spring::Operational
 }
-fn could_be_dry(c: &u8) -> bool {
-    match c {
-        1 => false,
-        0 | 2 => true,
-        _ => panic!(),
-    }
+fn could_be_dry(c: &Spring) -> bool {
+    c != &Spring::Damaged
 }
 
 fn remaining_springs(groups: &Vec<usize>, result: &[usize]) -> i64 {
@@ -109,7 +111,7 @@ impl Memo {
         gidx: usize,
         ingroup: bool,
         groups: &mut Vec<usize>,
-        data: &Vec<u8>,
+        data: &Vec<Spring>,
         result: &Vec<usize>,
     ) -> i64 {
         let key = (position, gidx, ingroup, groups.to_owned());
@@ -127,7 +129,7 @@ impl Memo {
         gidx: usize,
         ingroup: bool,
         groups: &mut Vec<usize>,
-        data: &Vec<u8>,
+        data: &Vec<Spring>,
         result: &Vec<usize>,
     ) -> i64 {
         let n = data.len();
@@ -177,7 +179,7 @@ impl Memo {
                     return 0;
                 }
                 let mut pos = cur_group_end + 1;
-                while pos < n && data[pos] == 0 {
+                while pos < n && data[pos] == Spring::Operational {
                     // we run until we find a # or ?
                     pos += 1;
                 }
@@ -185,17 +187,17 @@ impl Memo {
                     // we ran out but where not in the last group
                     return 0;
                 }
-                // here we are sure that some chars are remaining and that the current char is not 0
+                // here we are sure that some chars are remaining and that the current char is not operational
 
-                // value for case = 1
+                // value for a damaged spring
                 let mut groups_cpy = groups.clone();
                 groups_cpy[gidx + 1] += 1;
 
                 let val = self.memarrang(pos + 1, gidx + 1, ingroup, &mut groups_cpy, data, result);
-                if data[pos] == 1 {
+                if data[pos] == Spring::Damaged {
                     return val;
                 }
-                // in case of 2 we need to also compute the val for the . case
+                // in case of an unknown spring we need to also compute the val for the . case
                 val + self.memarrang(pos + 1, gidx + 1, false, groups, data, result)
             }
         } else {
@@ -211,7 +213,7 @@ impl Memo {
             // if we get here a new group can be started
             // not in a group we proceed until a # or ? potential group
             let mut pos = position;
-            while pos < n && data[pos] == 0 {
+            while pos < n && data[pos] == Spring::Operational {
                 // we run until we find a # or ?
                 pos += 1;
             }
@@ -222,10 +224,10 @@ impl Memo {
             let mut groups_cpy = groups.clone();
             groups_cpy[ngidx] += 1;
             let val = self.memarrang(pos + 1, ngidx, true, &mut groups_cpy, data, result);
-            if data[pos] == 1 {
+            if data[pos] == Spring::Damaged {
                 return val;
             }
-            // if we get here its a 2
+            // if we get here its unknown
             val + self.memarrang(pos + 1, gidx, ingroup, groups, data, result)
         }
     }
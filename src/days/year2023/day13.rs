@@ -0,0 +1,75 @@
+use crate::util::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Terrain {
+    Ash,
+    Rock,
+}
+impl FromChar for Terrain {
+    fn default() -> Self {
+        Self::Ash
+    }
+    fn from_char(char: &char) -> Option<Self> {
+        match char {
+            '.' => Some(Self::Ash),
+            '#' => Some(Self::Rock),
+            _ => None,
+        }
+    }
+}
+impl MatrixElement for Terrain {}
+impl ToChar for Terrain {
+    fn to_char(&self) -> char {
+        match self {
+            Self::Ash => '.',
+            Self::Rock => '#',
+        }
+    }
+}
+
+/// number of elements that differ when mirroring the pattern between row `idx` and `idx + 1`, the
+/// columns are checked on the transposed pattern
+fn mirror_distance(pattern: &TransformedView<Terrain>, idx: i64) -> usize {
+    (0..=idx)
+        .rev()
+        .zip(idx + 1..pattern.height())
+        .filter_map(|(a, b)| Some((pattern.row(a)?, pattern.row(b)?)))
+        .map(|(a, b)| a.zip(b).filter(|(a, b)| a != b).count())
+        .sum()
+}
+/// sum of the rows above every mirror with the given distance
+fn mirror_rows(pattern: &TransformedView<Terrain>, distance: usize) -> i64 {
+    (0..(pattern.height() - 1))
+        .filter(|&row| mirror_distance(pattern, row) == distance)
+        .map(|row| row + 1)
+        .sum()
+}
+
+/// the patterns of ash and rocks
+pub type Puzzle = Vec<Matrix<Terrain>>;
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    input.split("\n\n").map(Matrix::from_string).collect()
+}
+
+pub fn part1(patterns: &Puzzle) -> Result<i64, SolveError> {
+    // test vertical
+    let mut solution = 0;
+    for pattern in patterns {
+        solution += mirror_rows(&pattern.transformed_view(Transform::Identity), 0) * 100;
+    }
+    Ok(solution)
+}
+pub fn part2(patterns: &Puzzle) -> Result<i64, SolveError> {
+    let desired_distance = 0;
+    // test vertical
+    let mut solution = 0;
+    for pattern in patterns {
+        let rows = pattern.transformed_view(Transform::Identity);
+        let cols = pattern.transformed_view(Transform::Transpose);
+        solution += mirror_rows(&rows, desired_distance) * 100;
+        solution += mirror_rows(&cols, desired_distance);
+    }
+
+    Ok(solution)
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use crate::util::*;
+
+#[derive(Debug, Clone, PartialEq, Copy, Hash, Eq)]
+pub enum Element {
+    Empty,
+    Round,
+    Cube,
+}
+impl FromChar for Element {
+    fn default() -> Self {
+        Self::Empty
+    }
+    fn from_char(char: &char) -> Option<Self> {
+        match char {
+            '.' => Some(Element::Empty),
+            'O' => Some(Element::Round),
+            '#' => Some(Element::Cube),
+            _ => None,
+        }
+    }
+}
+impl MatrixElement for Element {}
+impl ToChar for Element {
+    fn to_char(&self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::Round => 'O',
+            Self::Cube => '#',
+        }
+    }
+}
+
+/// the platform with its rocks
+pub type Puzzle = Matrix<Element>;
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    Matrix::from_string(input)
+}
+
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let mut board = puzzle.clone();
+    tilt(&mut board);
+    Ok(compute_load(&board))
+}
+
+/// rolls the round rocks north until they hit the edge, a cube or another round rock
+fn tilt(board: &mut Matrix<Element>) {
+    for col in 0..board.width() {
+        let mut free = 0;
+        for row in 0..board.height() {
+            let idx = MatrixIdx { row, col };
+            match board[idx] {
+                Element::Round => {
+                    board[idx] = Element::Empty;
+                    board[MatrixIdx { row: free, col }] = Element::Round;
+                    free += 1;
+                }
+                Element::Cube => free = row + 1,
+                Element::Empty => (),
+            }
+        }
+    }
+}
+fn cycle(board: &mut Matrix<Element>) {
+    for _ in 0..4 {
+        tilt(board);
+        board.rotate_cw();
+    }
+}
+fn compute_load(board: &Matrix<Element>) -> i64 {
+    let mut load = 0;
+    for (w, row) in board.rows().rev().enumerate() {
+        load += (w + 1) * row.iter().filter(|e| e == &&Element::Round).count();
+    }
+    load as i64
+}
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let mut board = puzzle.clone();
+    const NCYCLES: usize = 1000000000;
+    let mut set = HashMap::new();
+
+    let mut i = 0;
+
+    while !set.contains_key(&board) {
+        set.insert(board.clone(), i);
+        cycle(&mut board);
+        i += 1;
+    }
+    let start = set[&board];
+    log::debug!(
+        "board repeats every {} cycles after {start} cycles",
+        i - start
+    );
+    let rem = (NCYCLES - i) % (i - start);
+
+    for _ in 0..rem {
+        cycle(&mut board);
+    }
+    Ok(compute_load(&board))
+}
@@ -1,4 +1,6 @@
-#[derive(Debug)]
+use crate::util::{ParseError, SolveError};
+
+#[derive(Debug, Clone)]
 enum Operation {
     Insert(usize, Lense),
     Remove(usize, String),
@@ -63,26 +65,31 @@ fn remove(lbox: &mut Vec<Lense>, label: &str) {
     }
 }
 
-fn parse_operation(text: &str) -> Operation {
-    let mut iter = text.chars();
-    let mut label = String::new();
-    let mut op = ' ';
-    for c in iter.by_ref() {
-        if c == '=' || c == '-' {
-            op = c;
-            break;
-        } else {
-            label.push(c);
-        }
-    }
+fn parse_operation(text: &str) -> Result<Operation, ParseError> {
+    let Some(opidx) = text.find(['=', '-']) else {
+        return Err(ParseError::at_end(text, "expected '=' or '-'"));
+    };
+    let label = text[..opidx].to_owned();
 
     let id = hash(&label);
-    if op == '-' {
-        Operation::Remove(id, label)
+    let focal_str = &text[opidx + 1..];
+    if text[opidx..].starts_with('-') {
+        if !focal_str.is_empty() {
+            return Err(ParseError::at(focal_str, "unexpected characters after '-'"));
+        }
+        Ok(Operation::Remove(id, label))
     } else {
-        let focal = iter.next().unwrap() as usize - '0' as usize;
+        let focal = match focal_str.chars().next().and_then(|c| c.to_digit(10)) {
+            Some(focal) if focal_str.len() == 1 => focal as usize,
+            _ => {
+                return Err(ParseError::at(
+                    focal_str,
+                    format!("invalid focal length '{focal_str}'"),
+                ))
+            }
+        };
         let lense = Lense { focal, label };
-        Operation::Insert(id, lense)
+        Ok(Operation::Insert(id, lense))
     }
 }
 
@@ -99,18 +106,35 @@ fn hash(chars: &str) -> usize {
     hash
 }
 
-pub fn part1(input: &str) -> i64 {
+/// the initialization sequence, part 1 only hashes the raw steps, so a malformed operation is
+/// only an error for part 2
+pub struct Puzzle {
+    steps: Vec<String>,
+    operations: Result<Vec<Operation>, ParseError>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let steps = input.split(',').map(str::to_owned).collect();
+    let operations = input
+        .trim_end()
+        .split(',')
+        .map(parse_operation)
+        .collect::<Result<_, _>>()
+        .map_err(|err| err.locate(input));
+    Ok(Puzzle { steps, operations })
+}
+
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
     let mut solution = 0;
-    for split in input.split(',') {
+    for split in &puzzle.steps {
         solution += hash(split);
     }
-    solution as i64
+    Ok(solution as i64)
 }
-pub fn part2(input: &str) -> i64 {
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
     let mut boxes = Boxes::new();
-    for split in input.split(',') {
-        let op = parse_operation(split);
+    for op in puzzle.operations.clone()? {
         boxes.apply(op);
     }
-    boxes.focusing_power()
+    Ok(boxes.focusing_power())
 }
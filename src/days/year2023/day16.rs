@@ -1,5 +1,7 @@
 use std::{collections::HashSet, ops::Index};
 
+use crate::util::{ParseError, SolveError};
+
 #[derive(Clone, Copy, Debug)]
 enum Element {
     Empty,
@@ -23,14 +25,14 @@ struct NodeIdx {
 }
 
 impl Element {
-    fn from_char(char: char) -> Self {
+    fn from_char(char: char) -> Option<Self> {
         match char {
-            '.' => Element::Empty,
-            '\\' => Element::BMirror,
-            '/' => Element::FMirror,
-            '-' => Element::HSplit,
-            '|' => Element::VSplit,
-            _ => panic!(),
+            '.' => Some(Element::Empty),
+            '\\' => Some(Element::BMirror),
+            '/' => Some(Element::FMirror),
+            '-' => Some(Element::HSplit),
+            '|' => Some(Element::VSplit),
+            _ => None,
         }
     }
     fn out_dirs(&self, dir: &Direction) -> Vec<Direction> {
@@ -55,7 +57,8 @@ impl Element {
         }
     }
 }
-struct Board {
+#[derive(Debug)]
+pub struct Board {
     data: Vec<Element>,
     width: usize,
 }
@@ -70,17 +73,25 @@ impl Board {
     fn linidx(&self, idx: &NodeIdx) -> usize {
         idx.row * self.width + idx.col
     }
-    fn from_string(input: &str) -> Self {
+    fn from_string(input: &str) -> Result<Self, ParseError> {
         let mut data = Vec::new();
         let mut width = 0;
-        for line in input.lines() {
+        for (row, line) in input.lines().enumerate() {
+            if row > 0 && line.len() != width {
+                return Err(ParseError::at(line, format!("expected {width} columns")));
+            }
             width = line.len();
-            for c in line.chars() {
-                data.push(Element::from_char(c));
+            for (idx, c) in line.char_indices() {
+                let element = Element::from_char(c)
+                    .ok_or_else(|| ParseError::at(&line[idx..], format!("invalid tile '{c}'")))?;
+                data.push(element);
             }
         }
+        if width == 0 {
+            return Err(ParseError::at(input, "empty contraption"));
+        }
 
-        Self { data, width }
+        Ok(Self { data, width })
     }
     fn height(&self) -> usize {
         self.data.len() / self.width
@@ -131,14 +142,19 @@ impl Board {
         visited_idx.len() as i64
     }
 }
-pub fn part1(input: &str) -> i64 {
-    let board = Board::from_string(input);
+/// the contraption of mirrors and splitters
+pub type Puzzle = Board;
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    Board::from_string(input)
+}
+
+pub fn part1(board: &Puzzle) -> Result<i64, SolveError> {
     let start = NodeIdx { row: 0, col: 0 };
     let dir = Direction::Right;
-    board.compute_energy((start, dir))
+    Ok(board.compute_energy((start, dir)))
 }
-pub fn part2(input: &str) -> i64 {
-    let board = Board::from_string(input);
+pub fn part2(board: &Puzzle) -> Result<i64, SolveError> {
     let n = board.height();
     let m = board.width;
     let mut starts = Vec::new();
@@ -154,5 +170,23 @@ pub fn part2(input: &str) -> i64 {
         .iter()
         .map(|&node| board.compute_energy(node))
         .max()
-        .unwrap()
+        .ok_or_else(|| SolveError::unsolvable("the contraption has no tiles"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::Location;
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse("").unwrap_err().message, "empty contraption");
+        let input = ".|.\n.x.\n";
+        let err = parse(input).unwrap_err().locate(input);
+        assert_eq!(err.message, "invalid tile 'x'");
+        assert_eq!(err.location, Some(Location { line: 2, column: 2 }));
+        assert_eq!(
+            parse(".|.\n..\n").unwrap_err().message,
+            "expected 3 columns"
+        );
+    }
 }
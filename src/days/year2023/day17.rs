@@ -0,0 +1,73 @@
+use crate::util::search::dijkstra;
+use crate::util::*;
+
+#[derive(Hash, Debug, PartialEq, Eq, Clone, Copy)]
+struct State {
+    idx: MatrixIdx,
+    /// direction of the last move, none at the start
+    direction: Option<Direction>,
+    /// number of moves in a row in `direction`
+    repetition: u8,
+}
+
+/// the crucible may turn after moving at least `min` blocks in a row and has to turn after `max`
+fn next_states(board: &Matrix<i64>, state: &State, min: u8, max: u8) -> Vec<(State, usize)> {
+    ALL_DIRECTIONS
+        .iter()
+        .filter(|dir| match state.direction {
+            None => true,
+            Some(last) if last == **dir => state.repetition < max,
+            Some(last) => last.opposite() != **dir && state.repetition >= min,
+        })
+        .filter_map(|dir| {
+            let idx = board.next(&state.idx, dir)?;
+            let repetition = if state.direction == Some(*dir) {
+                state.repetition + 1
+            } else {
+                1
+            };
+            let next = State {
+                idx,
+                direction: Some(*dir),
+                repetition,
+            };
+            Some((next, board[idx] as usize))
+        })
+        .collect()
+}
+/// least heat lost on the way from the top left to the bottom right block
+fn min_heat_loss(board: &Matrix<i64>, min: u8, max: u8) -> Result<i64, SolveError> {
+    let start = State {
+        idx: MatrixIdx { row: 0, col: 0 },
+        direction: None,
+        repetition: 0,
+    };
+    let goal = MatrixIdx {
+        row: board.height() - 1,
+        col: board.width() - 1,
+    };
+    let search = dijkstra(
+        [start],
+        |state| next_states(board, state, min, max),
+        |state| state.idx == goal && state.repetition >= min,
+    );
+    log::debug!("{} states reached", search.distances.len());
+    let goal = search
+        .goal
+        .ok_or_else(|| SolveError::unsolvable("the bottom right block can not be reached"))?;
+    Ok(search.distances[&goal] as i64)
+}
+
+/// the heat loss map of the city blocks
+pub type Puzzle = Matrix<i64>;
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    Matrix::from_string(input)
+}
+
+pub fn part1(board: &Puzzle) -> Result<i64, SolveError> {
+    min_heat_loss(board, 1, 3)
+}
+pub fn part2(board: &Puzzle) -> Result<i64, SolveError> {
+    min_heat_loss(board, 4, 10)
+}
@@ -0,0 +1,127 @@
+use crate::util::*;
+
+#[derive(Debug, PartialEq)]
+struct Instruction {
+    direction: Direction,
+    distance: i64,
+}
+
+fn dig(pos: &MatrixIdx, instruction: &Instruction) -> MatrixIdx {
+    let MatrixIdx { row, col } = pos;
+    let row = match instruction.direction {
+        Direction::Down => row + instruction.distance,
+        Direction::Up => row - instruction.distance,
+        Direction::Left | Direction::Right => *row,
+    };
+    let col = match instruction.direction {
+        Direction::Left => col - instruction.distance,
+        Direction::Right => col + instruction.distance,
+        Direction::Up | Direction::Down => *col,
+    };
+    MatrixIdx { row, col }
+}
+
+fn parse_line(line: &str) -> Result<Instruction, ParseError> {
+    let mut parts = line.split_whitespace();
+    let dir = next_token(&mut parts, line, "direction")?;
+    let direction = dir
+        .chars()
+        .next()
+        .and_then(Direction::from_char)
+        .filter(|_| dir.len() == 1)
+        .ok_or_else(|| ParseError::at(dir, format!("invalid direction '{dir}'")))?;
+    let distance = parse_num::<i64>(next_token(&mut parts, line, "distance")?)?;
+
+    Ok(Instruction {
+        direction,
+        distance,
+    })
+}
+
+fn parse_line2(line: &str) -> Result<Instruction, ParseError> {
+    let mut parts = line.split_whitespace().skip(2);
+    let color = next_token(&mut parts, line, "color")?;
+    let Some(hex) = color
+        .strip_prefix("(#")
+        .and_then(|color| color.strip_suffix(')'))
+        .filter(|hex| hex.len() == 6)
+    else {
+        return Err(ParseError::at(color, format!("invalid color '{color}'")));
+    };
+    let (color_str, dir) = hex.split_at(5);
+    let direction = match dir {
+        "0" => Direction::Right,
+        "1" => Direction::Down,
+        "2" => Direction::Left,
+        "3" => Direction::Up,
+        _ => return Err(ParseError::at(dir, format!("invalid direction '{dir}'"))),
+    };
+    let distance = i64::from_str_radix(color_str, 16)
+        .map_err(|_| ParseError::at(color_str, format!("invalid distance '{color_str}'")))?;
+    Ok(Instruction {
+        direction,
+        distance,
+    })
+}
+
+fn sholace(idzs: &[MatrixIdx]) -> i64 {
+    // A = 1/2 Sum (yi + yi1)*(xi - xi1)
+    let mut area = 0;
+    let mut curr = idzs[0];
+    for next in idzs.iter().skip(1) {
+        let xi = curr.col;
+        let yi = curr.row;
+        let xii = next.col;
+        let yii = next.row;
+        area += xi * yii - xii * yi;
+
+        curr = *next;
+    }
+    area / 2
+}
+
+/// the dig plan, read once from the directions and once from the colors
+pub struct Puzzle {
+    plan: Result<Vec<Instruction>, ParseError>,
+    color_plan: Result<Vec<Instruction>, ParseError>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let plan = input.lines().map(parse_line).collect::<Result<_, _>>();
+    let color_plan = input.lines().map(parse_line2).collect::<Result<_, _>>();
+    Ok(Puzzle {
+        plan: plan.map_err(|err| err.locate(input)),
+        color_plan: color_plan.map_err(|err| err.locate(input)),
+    })
+}
+
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let instructions = puzzle.plan.as_ref().map_err(Clone::clone)?;
+
+    let mut position = MatrixIdx { row: 0, col: 0 };
+    let mut positions = vec![position];
+    for instruction in instructions.iter() {
+        position = dig(&position, instruction);
+        positions.push(position);
+    }
+
+    let a = sholace(&positions);
+    let b = instructions
+        .iter()
+        .fold(0, |peri, inst| peri + inst.distance);
+    Ok(a + b / 2 + 1)
+}
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let instructions = puzzle.color_plan.as_ref().map_err(Clone::clone)?;
+    let mut position = MatrixIdx { row: 0, col: 0 };
+    let mut positions = vec![position];
+    for instruction in instructions.iter() {
+        position = dig(&position, instruction);
+        positions.push(position);
+    }
+    let a = sholace(&positions);
+    let b = instructions
+        .iter()
+        .fold(0, |peri, inst| peri + inst.distance);
+    Ok(a + b / 2 + 1)
+}
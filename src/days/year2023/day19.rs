@@ -1,5 +1,7 @@
 use std::cmp::{max, min};
 use std::collections::HashMap;
+
+use crate::util::{parse_num, split_once, ParseError, SolveError};
 enum WorkflowResult<'a> {
     Accept,
     Reject,
@@ -115,62 +117,99 @@ struct Workflow {
     default: String,
 }
 
-fn parse_rule(rule: &str) -> Rule {
-    let (rule, target) = rule.split_once(':').unwrap();
+fn parse_rule(rule: &str) -> Result<Rule, ParseError> {
+    let (condition, target) = split_once(rule, ":")?;
     let target = target.to_owned();
-    let mut chars = rule.chars();
-    let member = chars.next().unwrap();
-    let op = chars.next().unwrap();
-    let limit = chars.collect::<String>().parse::<i64>().unwrap();
-    Rule {
+    let mut chars = condition.chars();
+    let member = chars
+        .next()
+        .filter(|member| "xmas".contains(*member))
+        .ok_or_else(|| ParseError::at(condition, "expected one of x, m, a, s"))?;
+    let op = chars
+        .next()
+        .filter(|op| "<>".contains(*op))
+        .ok_or_else(|| ParseError::at(&condition[1..], "expected < or >"))?;
+    let limit = parse_num::<i64>(&condition[2..])?;
+    Ok(Rule {
         member,
         op,
         limit,
         target,
-    }
+    })
 }
 
-fn parse_workflow(line: &str) -> (String, Workflow) {
-    let (name, rest) = line.split_once('{').unwrap();
+fn parse_workflow(line: &str) -> Result<(String, Workflow), ParseError> {
+    let (name, rest) = split_once(line, "{")?;
+    let rest = rest
+        .strip_suffix('}')
+        .ok_or_else(|| ParseError::at_end(line, "expected '}'"))?;
     let mut iter = rest.split(',').rev();
-    let default = iter.next().unwrap().replace('}', "");
+    // split always yields at least one element
+    let default = iter.next().unwrap().to_owned();
     let mut rules = Vec::new();
     for rule_str in iter.rev() {
-        rules.push(parse_rule(rule_str))
+        rules.push(parse_rule(rule_str)?)
     }
 
-    (name.to_owned(), Workflow { rules, default })
+    Ok((name.to_owned(), Workflow { rules, default }))
 }
 
-fn parse_part(line: &str) -> Part {
-    let line = line.replace(['{', '}'], "");
-    line.split(',')
+fn parse_part(line: &str) -> Result<Part, ParseError> {
+    let members = line
+        .strip_prefix('{')
+        .and_then(|line| line.strip_suffix('}'))
+        .ok_or_else(|| ParseError::at(line, "expected part enclosed in '{}'"))?;
+    let part: Part = members
+        .split(',')
         .map(|member| {
-            let (_, num) = member.split_once('=').unwrap();
-            num.parse::<i64>().unwrap()
+            let (_, num) = split_once(member, "=")?;
+            parse_num::<i64>(num)
         })
-        .collect()
+        .collect::<Result<_, _>>()?;
+    if part.len() != 4 {
+        return Err(ParseError::at(line, "expected ratings for x, m, a, s"));
+    }
+    Ok(part)
+}
+
+/// the workflows and the parts to sort
+pub struct Puzzle {
+    workflows: Workflows,
+    parts: Vec<Part>,
 }
 
-fn parse_input(input: &str) -> (Workflows, Vec<Part>) {
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
     let mut workflows = HashMap::new();
-    let (workflow_lines, object_lines) = input.split_once("\n\n").unwrap();
+    let (workflow_lines, object_lines) = split_once(input, "\n\n")?;
     for line in workflow_lines.lines() {
         if line.is_empty() {
             break;
         }
-        let (name, workflow) = parse_workflow(line);
+        let (name, workflow) = parse_workflow(line)?;
         workflows.insert(name, workflow);
     }
+    if !workflows.contains_key("in") {
+        return Err(ParseError::at(input, "missing workflow 'in'"));
+    }
+    // every target needs to be a known workflow, otherwise the evaluation gets stuck
+    for line in workflow_lines.lines() {
+        let workflow = &workflows[split_once(line, "{")?.0];
+        let targets = workflow.rules.iter().map(|rule| &rule.target);
+        for target in targets.chain([&workflow.default]) {
+            if target != "A" && target != "R" && !workflows.contains_key(target) {
+                return Err(ParseError::at(line, format!("unknown workflow '{target}'")));
+            }
+        }
+    }
 
     // parse parts
     let mut parts = Vec::new();
 
     for line in object_lines.lines() {
-        parts.push(parse_part(line));
+        parts.push(parse_part(line)?);
     }
 
-    (workflows, parts)
+    Ok(Puzzle { workflows, parts })
 }
 fn apply_rule<'a>(rule: &'a Rule, part: &Part) -> Option<&'a str> {
     let Rule {
@@ -227,25 +266,25 @@ fn run_workflow_range<'a>(workflow: &'a Workflow, part: &PartRange) -> Vec<(&'a
     nexts
 }
 
-pub fn part1(input: &str) -> i64 {
-    let (workflows, parts) = parse_input(input);
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let Puzzle { workflows, parts } = puzzle;
     let mut sum = 0;
     for part in parts {
         let mut workflow = &workflows["in"];
-        let mut result = run_workflow(workflow, &part);
+        let mut result = run_workflow(workflow, part);
         while let WorkflowResult::Next(next) = result {
             workflow = &workflows[next];
-            result = run_workflow(workflow, &part);
+            result = run_workflow(workflow, part);
         }
         sum += match result {
             WorkflowResult::Accept => part.iter().sum::<i64>(),
             _ => 0,
         };
     }
-    sum
+    Ok(sum)
 }
-pub fn part2(input: &str) -> i64 {
-    let (workflows, _) = parse_input(input);
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let workflows = &puzzle.workflows;
 
     let mut parts = vec![("in", PartRange::new())];
     let mut accepted = Vec::new();
@@ -280,5 +319,5 @@ pub fn part2(input: &str) -> i64 {
         }
     }
 
-    sum
+    Ok(sum)
 }
@@ -0,0 +1,74 @@
+use crate::util::{next_token, parse_num, split_once, ParseError, SolveError};
+
+const MAXRED: i32 = 12;
+const MAXGREEN: i32 = 13;
+const MAXBLUE: i32 = 14;
+
+/// id of a game and the most cubes of each color shown at once
+struct Game {
+    id: u64,
+    maxcubes: (i32, i32, i32),
+}
+pub struct Puzzle {
+    games: Vec<Game>,
+}
+
+fn maxcubes<'a, I>(shows: I) -> Result<(i32, i32, i32), ParseError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let mut reds = vec![];
+    let mut greens = vec![];
+    let mut blues = vec![];
+    for show in shows {
+        for col in show.split(',') {
+            let mut split = col.split_whitespace();
+            let cnt = parse_num::<i32>(next_token(&mut split, col, "cube count")?)?;
+            let color = next_token(&mut split, col, "cube color")?;
+            match color {
+                "red" => reds.push(cnt),
+                "green" => greens.push(cnt),
+                "blue" => blues.push(cnt),
+                _ => return Err(ParseError::at(color, format!("unknown color '{color}'"))),
+            }
+        }
+    }
+    Ok((
+        reds.iter().max().copied().unwrap_or(0),
+        greens.iter().max().copied().unwrap_or(0),
+        blues.iter().max().copied().unwrap_or(0),
+    ))
+}
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let mut games = vec![];
+    for game in input.lines() {
+        let (name, shows) = split_once(game, ":")?;
+        let mut name = name.split(' ');
+        name.next();
+        let id = parse_num::<u64>(next_token(&mut name, game, "game id")?)?;
+        let maxcubes = maxcubes(shows.split(';'))?;
+        games.push(Game { id, maxcubes });
+    }
+    Ok(Puzzle { games })
+}
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let mut solution = 0;
+    for game in &puzzle.games {
+        let (maxred, maxgreen, maxblue) = game.maxcubes;
+        let valid = maxred <= MAXRED && maxgreen <= MAXGREEN && maxblue <= MAXBLUE;
+        if valid {
+            solution += game.id;
+        }
+    }
+    Ok(solution as i64)
+}
+
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let mut solution = 0;
+    for game in &puzzle.games {
+        let (maxred, maxgreen, maxblue) = game.maxcubes;
+
+        solution += maxred * maxgreen * maxblue;
+    }
+    Ok(solution as i64)
+}
@@ -1,18 +1,20 @@
 use std::collections::HashMap;
+
+use crate::util::{split_once, ParseError, SolveError};
 #[derive(Debug)]
 struct Signal {
     sender: usize,
     target: usize,
     level: bool,
 }
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Module {
     FlipFlop(FlipFlop),
     BroadCaster,
     Conjunction(Conjunction),
     Untyped,
 }
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Conjunction {
     levels: HashMap<usize, bool>,
 }
@@ -22,18 +24,23 @@ impl Conjunction {
             levels: inputs.iter().map(|&idx| (idx, false)).collect(),
         }
     }
+    /// remembers the last pulse of every input, sends a low pulse once all of them are high
     fn receive(&mut self, input: usize, pulse: bool) -> Option<bool> {
-        if let Some(value) = self.levels.get_mut(&input) {
-            *value = pulse;
-            Some(!self.levels.values().all(|b| *b))
-        } else {
-            let keys: Vec<&usize> = self.levels.keys().collect();
-            panic!("invalid input idx '{input}', allowed are {keys:#?}");
-        }
+        self.levels.insert(input, pulse);
+        Some(!self.levels.values().all(|b| *b))
     }
 }
 
-#[derive(Debug)]
+/// type of a module as written in the configuration, modules that are only targets are untyped
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    BroadCaster,
+    FlipFlop,
+    Conjunction,
+    Untyped,
+}
+
+#[derive(Debug, Clone)]
 struct FlipFlop {
     state: bool,
 }
@@ -51,8 +58,8 @@ impl FlipFlop {
     }
 }
 
-#[derive(Debug)]
-struct Network {
+#[derive(Debug, Clone)]
+pub struct Network {
     idxmap: HashMap<String, usize>,
     // names: Vec<String>,
     modules: Vec<Module>,
@@ -95,41 +102,57 @@ impl Network {
         }
         newsignals
     }
-    fn find(&self, name: &str) -> usize {
-        self.idxmap[name]
+    fn find(&self, name: &str) -> Result<usize, ParseError> {
+        self.idxmap
+            .get(name)
+            .copied()
+            .ok_or_else(|| ParseError::new(format!("missing module '{name}'")))
     }
     fn solution(&self) -> i64 {
         self.highcnt * self.lowcnt
     }
 }
 
-fn parse_input1(input: &str) -> Network {
+/// the module configuration with all modules in their initial state
+pub type Puzzle = Network;
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
     let mut idxmap: HashMap<String, usize> = HashMap::from([("button".to_owned(), 0)]);
-    let mut module_kinds = vec!["u"];
+    let mut module_kinds = vec![Kind::Untyped];
     let mut output_names = vec![vec!["broadcaster".to_owned()]];
 
     let mut names: Vec<String> = vec!["button".to_owned()];
 
     for (idx, line) in input.lines().enumerate() {
-        let (left, right) = line.split_once("->").unwrap();
+        let (left, right) = split_once(line, "->")?;
         let targets: Vec<String> = right.split(',').map(|s| s.trim().to_owned()).collect();
 
         let (name, kind) = match left.trim() {
-            "broadcaster" => ("broadcaster".to_owned(), "broadcaster"),
+            "broadcaster" => ("broadcaster".to_owned(), Kind::BroadCaster),
             left if left.starts_with('%') => {
                 let mut name = left.to_owned();
                 name.remove(0);
-                (name, "%")
+                (name, Kind::FlipFlop)
             }
             left if left.starts_with('&') => {
                 let mut name = left.to_owned();
                 name.remove(0);
-                (name, "&")
+                (name, Kind::Conjunction)
+            }
+            _ => {
+                return Err(ParseError::at(
+                    left.trim_start(),
+                    format!("invalid module '{}'", left.trim()),
+                ))
             }
-            _ => panic!("should not happen! {left}"),
         };
 
-        idxmap.insert(name.clone(), idx + 1);
+        if idxmap.insert(name.clone(), idx + 1).is_some() {
+            return Err(ParseError::at(
+                left.trim_start(),
+                format!("module '{name}' is defined twice"),
+            ));
+        }
         names.push(name);
         module_kinds.push(kind);
         output_names.push(targets);
@@ -141,7 +164,7 @@ fn parse_input1(input: &str) -> Network {
         let key = name.as_str();
         if !idxmap.contains_key(key) {
             // otherwise we insert trivial module
-            module_kinds.push("u");
+            module_kinds.push(Kind::Untyped);
             idxmap.insert(name.clone(), idx);
             names.push(name.clone());
 
@@ -170,28 +193,27 @@ fn parse_input1(input: &str) -> Network {
     let modules: Vec<Module> = module_kinds
         .iter()
         .enumerate()
-        .map(|(idx, &kind)| match kind {
-            "broadcaster" => Module::BroadCaster,
-            "%" => Module::FlipFlop(FlipFlop::new()),
-            "&" => Module::Conjunction(Conjunction::from_inputs(&inputs[idx])),
-            "u" => Module::Untyped,
-            _ => panic!("should not happen! {kind}"),
+        .map(|(idx, kind)| match kind {
+            Kind::BroadCaster => Module::BroadCaster,
+            Kind::FlipFlop => Module::FlipFlop(FlipFlop::new()),
+            Kind::Conjunction => Module::Conjunction(Conjunction::from_inputs(&inputs[idx])),
+            Kind::Untyped => Module::Untyped,
         })
         .collect();
 
-    Network {
+    Ok(Network {
         idxmap,
         modules,
         outputs,
         // inputs,
         highcnt: 0,
         lowcnt: 0,
-    }
+    })
 }
 
-pub fn part1(input: &str) -> i64 {
-    let mut network = parse_input1(input);
-    let target = network.find("broadcaster");
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let mut network = puzzle.clone();
+    let target = network.find("broadcaster")?;
     for _ in 0..1000 {
         let mut signals = vec![Signal {
             sender: 0,
@@ -203,13 +225,21 @@ pub fn part1(input: &str) -> i64 {
         }
     }
 
-    network.solution()
+    Ok(network.solution())
 }
-pub fn part2(input: &str) -> i64 {
-    let mut network = parse_input1(input);
-    let broadcaster = network.find("broadcaster");
-    let button = network.find("button");
-    let target = network.find("zg");
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let mut network = puzzle.clone();
+    let broadcaster = network.find("broadcaster")?;
+    let button = network.find("button")?;
+    let target = network.find("zg")?;
+    match &network.modules[target] {
+        Module::Conjunction(conj) if conj.levels.len() == 4 => (),
+        _ => {
+            return Err(SolveError::unsolvable(
+                "'zg' must be a conjunction of 4 inputs",
+            ))
+        }
+    }
 
     let mut iterations = [0; 4];
     for (i, iter) in iterations.iter_mut().enumerate() {
@@ -236,5 +266,22 @@ pub fn part2(input: &str) -> i64 {
             *iter = iteration;
         }
     }
-    iterations.iter().product()
+    Ok(iterations.iter().product())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_parse_errors() {
+        let message = |input: &str| parse(input).err().map(|err| err.message);
+        assert_eq!(
+            message("broadcaster -> a\n?a -> b\n"),
+            Some("invalid module '?a'".to_owned())
+        );
+        assert_eq!(
+            message("broadcaster -> a\n%a -> b\n&a -> b\n"),
+            Some("module 'a' is defined twice".to_owned())
+        );
+    }
 }
@@ -0,0 +1,125 @@
+use nalgebra::{DMatrix, DVector};
+use std::collections::HashSet;
+
+use crate::util::search::bfs;
+use crate::util::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Plots {
+    Rock,
+    Garden,
+    Start,
+}
+
+impl FromChar for Plots {
+    fn default() -> Self {
+        Self::Garden
+    }
+    fn from_char(char: &char) -> Option<Self> {
+        match char {
+            '#' => Some(Self::Rock),
+            '.' => Some(Self::Garden),
+            'S' => Some(Self::Start),
+            _ => None,
+        }
+    }
+}
+
+impl MatrixElement for Plots {}
+impl ToChar for Plots {
+    fn to_char(&self) -> char {
+        match self {
+            Self::Rock => '#',
+            Self::Garden => '.',
+            Self::Start => 'S',
+        }
+    }
+}
+
+fn take_step_inf(matrix: &Matrix<Plots>, positions: &HashSet<MatrixIdx>) -> HashSet<MatrixIdx> {
+    positions
+        .iter()
+        .flat_map(|position| matrix.neighbours_wrapped(position, Neighbourhood::Four))
+        .filter(|idx| matrix.get_wrapped(idx) != &Plots::Rock)
+        .collect()
+}
+/// the garden map
+pub type Puzzle = Matrix<Plots>;
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let matrix = Matrix::from_string(input)?;
+    if matrix.find(&Plots::Start).is_none() {
+        return Err(ParseError::at(input, "the map has no start 'S'"));
+    }
+    Ok(matrix)
+}
+
+pub fn part1(matrix: &Puzzle) -> Result<i64, SolveError> {
+    const STEPS: usize = 64;
+    let search = bfs(
+        matrix.find(&Plots::Start),
+        |idx| matrix.neighbour_idzs_filt(idx, |elem| elem != &Plots::Rock),
+        |_| false,
+    );
+    // every plot reached in fewer steps can be reached again by stepping back and forth
+    let positions: Vec<MatrixIdx> = search
+        .distances
+        .iter()
+        .filter(|(_, &distance)| distance <= STEPS && distance % 2 == STEPS % 2)
+        .map(|(idx, _)| *idx)
+        .collect();
+    log::trace!(
+        "reachable after {STEPS} steps:\n{}",
+        matrix.overlay().mark(positions.iter().copied(), 'O')
+    );
+    Ok(positions.len() as i64)
+}
+pub fn part2(matrix: &Puzzle) -> Result<i64, SolveError> {
+    let mut front: HashSet<MatrixIdx> = matrix.find(&Plots::Start).into_iter().collect();
+    let mut odd = HashSet::new();
+    let mut ys = Vec::new();
+    let mut xs = Vec::new();
+
+    for step in 1..5000 {
+        front = take_step_inf(matrix, &front);
+        if step % 2 == 1 {
+            front = front.difference(&odd).copied().collect();
+            odd.extend(front.iter());
+        }
+        if (step - 65) % 262 == 0 {
+            xs.push(step);
+            ys.push(odd.len() as i32)
+        }
+    }
+    let xs = DVector::from_vec(xs);
+    let ys = DVector::from_vec(ys).cast::<f64>();
+    let mut ones = xs.clone();
+    ones.fill(1);
+    let xs2 = xs.clone().component_mul(&xs);
+
+    let phi = DMatrix::from_columns(&[ones, xs, xs2]).cast::<f64>();
+    let pars = phi
+        .svd(true, true)
+        .solve(&ys, 1e-13)
+        .map_err(SolveError::unsolvable)?;
+    let cnt = 26501365f64;
+    let data = DVector::from_column_slice(&[1f64, cnt, cnt * cnt]);
+    Ok(pars.dot(&data).round() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse("").unwrap_err().message, "empty matrix");
+        assert_eq!(
+            parse("..#\n...\n").unwrap_err().message,
+            "the map has no start 'S'"
+        );
+        let input = ".S.\n.?.\n";
+        let err = parse(input).unwrap_err().locate(input);
+        assert_eq!(err.message, "invalid character '?'");
+        assert_eq!(err.location, Some(Location { line: 2, column: 2 }));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::util::parse_vec3;
+use crate::util::{parse_vec3, split_once, ParseError, SolveError};
 use nalgebra::Vector3;
 use std::collections::{HashMap, HashSet};
 type Position = Vector3<i64>;
@@ -105,26 +105,31 @@ impl BrickStack {
     }
 }
 
-fn parse_bricks(input: &str) -> Vec<(Position, Brick)> {
-    input
+/// the snapshot of the falling bricks, sorted by height
+pub struct Puzzle {
+    bricks: Vec<(Position, Brick)>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let mut bricks = input
         .lines()
         .map(|line| {
-            let (a, b) = line.split_once('~').unwrap();
-            Brick::from_start_end(&parse_vec3(a), &parse_vec3(b))
+            let (a, b) = split_once(line, "~")?;
+            Ok(Brick::from_start_end(&parse_vec3(a)?, &parse_vec3(b)?))
         })
-        .collect()
-}
-fn create_stack(input: &str) -> BrickStack {
-    let mut bricks = parse_bricks(input);
+        .collect::<Result<Vec<_>, ParseError>>()?;
     bricks.sort_by_key(|(a, _)| a.z);
+    Ok(Puzzle { bricks })
+}
+fn create_stack(puzzle: &Puzzle) -> BrickStack {
     let mut stack = BrickStack::new();
-    for (start, brick) in bricks {
+    for &(start, brick) in &puzzle.bricks {
         stack.stack(start, brick);
     }
     stack
 }
-pub fn part1(input: &str) -> i64 {
-    let stack = create_stack(input);
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let stack = create_stack(puzzle);
     let mut solution = 0;
     for above in stack.aboves.iter() {
         // if every of the above has more than one supports it can be disintegrated
@@ -132,11 +137,11 @@ pub fn part1(input: &str) -> i64 {
             solution += 1;
         }
     }
-    solution
+    Ok(solution)
 }
 
-pub fn part2(input: &str) -> i64 {
-    let stack = create_stack(input);
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let stack = create_stack(puzzle);
     let mut solution = 0i64;
 
     for (start, above) in stack.aboves.iter().enumerate() {
@@ -156,5 +161,5 @@ pub fn part2(input: &str) -> i64 {
         // dbg!(start, cnt);
         solution += cnt as i64;
     }
-    solution
+    Ok(solution)
 }
@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet};
 use crate::util::*;
 
 #[derive(Debug, PartialEq, Clone)]
-enum MapTile {
+pub enum MapTile {
     Path,
     Forest,
     NorthSlope,
@@ -15,16 +15,29 @@ impl FromChar for MapTile {
     fn default() -> Self {
         Self::Forest
     }
-    fn from_char(char: &char) -> Self {
+    fn from_char(char: &char) -> Option<Self> {
         use MapTile::*;
         match char {
-            '.' => Path,
-            '#' => Forest,
-            '^' => NorthSlope,
-            '>' => EastSlope,
-            '<' => WestSlope,
-            'v' => SouthSlope,
-            _ => panic!("should not happen!"),
+            '.' => Some(Path),
+            '#' => Some(Forest),
+            '^' => Some(NorthSlope),
+            '>' => Some(EastSlope),
+            '<' => Some(WestSlope),
+            'v' => Some(SouthSlope),
+            _ => None,
+        }
+    }
+}
+
+impl ToChar for MapTile {
+    fn to_char(&self) -> char {
+        match self {
+            Self::Path => '.',
+            Self::Forest => '#',
+            Self::NorthSlope => '^',
+            Self::EastSlope => '>',
+            Self::SouthSlope => 'v',
+            Self::WestSlope => '<',
         }
     }
 }
@@ -56,9 +69,15 @@ struct State {
     cost: i64,
 }
 
-pub fn part1(input: &str) -> i64 {
+/// the map of the hiking trails
+pub type Puzzle = Matrix<MapTile>;
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    Matrix::from_string(input)
+}
+
+pub fn part1(map: &Puzzle) -> Result<i64, SolveError> {
     let mut costmap = HashMap::new();
-    let map = Matrix::<MapTile>::from_string(input);
     let start = MatrixIdx { col: 1, row: 0 };
 
     let mut front = HashSet::from([State {
@@ -72,7 +91,7 @@ pub fn part1(input: &str) -> i64 {
     };
     while !front.is_empty() {
         let mut next_front = HashSet::new();
-        for state in front.into_iter().filter(|state| state_valid(state, &map)) {
+        for state in front.into_iter().filter(|state| state_valid(state, map)) {
             let State { idx, cost, .. } = state;
             if let Some(old) = costmap.get(&idx) {
                 if &cost > old {
@@ -85,7 +104,7 @@ pub fn part1(input: &str) -> i64 {
             }
             let neighbours = map.neighbour_idzs_dir(&idx);
             for (nidx, dir) in neighbours {
-                if state_input_valid(&state, &dir, &map) {
+                if state_input_valid(&state, &dir, map) {
                     let next = State {
                         idx: nidx,
                         last: dir,
@@ -97,7 +116,10 @@ pub fn part1(input: &str) -> i64 {
         }
         front = next_front;
     }
-    costmap[&goal]
+    costmap
+        .get(&goal)
+        .copied()
+        .ok_or_else(|| SolveError::unsolvable("the goal can not be reached"))
 }
 
 fn next_elems(idx: &MatrixIdx, next: &MatrixIdx, map: &Matrix<MapTile>) -> Vec<MatrixIdx> {
@@ -122,9 +144,8 @@ fn find_path(idx: &MatrixIdx, next: &MatrixIdx, map: &Matrix<MapTile>) -> (Matri
     (next, cnt + 1)
 }
 
-pub fn part2(input: &str) -> i64 {
+pub fn part2(map: &Puzzle) -> Result<i64, SolveError> {
     // let mut costmap = HashMap::new();
-    let map = Matrix::<MapTile>::from_string(input);
     let _visitmap: HashMap<MatrixIdx, HashSet<Vec<u8>>> = HashMap::new();
     let start = MatrixIdx { col: 1, row: 0 };
     let goal = MatrixIdx {
@@ -141,13 +162,13 @@ pub fn part2(input: &str) -> i64 {
         if neighbours.len() > 2 {
             graph.add_node(idx);
             for next in neighbours {
-                let (goal, distance) = find_path(&idx, &next, &map);
+                let (goal, distance) = find_path(&idx, &next, map);
                 graph.add_node(goal);
                 graph.add_edge(&idx, &goal, distance);
                 graph.add_edge(&goal, &idx, distance);
             }
         }
     }
-    graph.find_longest_path(&start, &goal) as i64
+    Ok(graph.find_longest_path(&start, &goal) as i64)
     // costmap[&goal]
 }
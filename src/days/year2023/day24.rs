@@ -1,4 +1,4 @@
-use crate::util::parse_vec3;
+use crate::util::{parse_num, parse_vec3, split_once, ParseError, SolveError};
 use nalgebra::{Matrix3, RowVector3, Vector2, Vector3};
 
 #[derive(Debug)]
@@ -54,21 +54,43 @@ impl XYRay {
         Some(Vector2::<f64>::from_column_slice(&[xc, yc]))
     }
 }
-fn parse_input(input: &str) -> Vec<(Vector3<i64>, Vector3<i64>)> {
+type Hailstone = (Vector3<i64>, Vector3<i64>);
+
+fn parse_input(input: &str) -> Result<Vec<Hailstone>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (pos, vel) = line.split_once('@').unwrap();
-            let pos = parse_vec3(pos);
-            let vel = parse_vec3(vel);
-            (pos, vel)
+            let (pos, vel) = split_once(line, "@")?;
+            let pos = parse_vec3(pos)?;
+            let vel = parse_vec3(vel)?;
+            Ok((pos, vel))
         })
         .collect()
 }
 const MIN: f64 = 200000000000000f64;
 const MAX: f64 = 400000000000000f64;
-pub fn part1(input: &str) -> i64 {
-    let data = parse_input(input);
+/// the hailstones, as integer vectors for part 1 and as floats for part 2
+pub struct Puzzle {
+    hailstones: Result<Vec<Hailstone>, ParseError>,
+    projectiles: Result<Vec<Projectile>, ParseError>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let projectiles = parse_input2(input).and_then(|projectiles| {
+        if projectiles.len() < 6 {
+            Err(ParseError::at_end(input, "expected at least 6 hailstones"))
+        } else {
+            Ok(projectiles)
+        }
+    });
+    Ok(Puzzle {
+        hailstones: parse_input(input).map_err(|err| err.locate(input)),
+        projectiles: projectiles.map_err(|err| err.locate(input)),
+    })
+}
+
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let data = puzzle.hailstones.as_ref().map_err(Clone::clone)?;
     let rays: Vec<XYRay> = data
         .iter()
         .map(|(pos, vel)| XYRay::from_pos_vel(pos, vel))
@@ -86,29 +108,29 @@ pub fn part1(input: &str) -> i64 {
             }
         }
     }
-    solution
+    Ok(solution)
+}
+
+fn parse_array3(string: &str) -> Result<[f64; 3], ParseError> {
+    let values: Vec<f64> = string
+        .split(',')
+        .map(|part| parse_num::<f64>(part.trim()))
+        .collect::<Result<_, _>>()?;
+    values
+        .try_into()
+        .map_err(|_| ParseError::at(string.trim_start(), "expected 3 coordinates"))
 }
 
-fn parse_input2(input: &str) -> Vec<Projectile> {
+fn parse_input2(input: &str) -> Result<Vec<Projectile>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (pos, vel) = line.split_once('@').unwrap();
+            let (pos, vel) = split_once(line, "@")?;
             // let pos = parse_vec3(pos);
-            let pos: Vec<f64> = pos
-                .trim()
-                .split(',')
-                .filter_map(|part| part.trim().parse::<f64>().ok())
-                .collect();
-            let vel: Vec<f64> = vel
-                .trim()
-                .split(',')
-                .filter_map(|part| part.trim().parse::<f64>().ok())
-                .collect();
-            Projectile {
-                start: [pos[0], pos[1], pos[2]],
-                velocity: [vel[0], vel[1], vel[2]],
-            }
+            Ok(Projectile {
+                start: parse_array3(pos)?,
+                velocity: parse_array3(vel)?,
+            })
         })
         .collect()
 }
@@ -132,7 +154,8 @@ fn exterior2(v: &[f64], w: &[f64]) -> Vec<f64> {
     ]
 }
 
-fn solve(projectiles: &[Projectile], idzs: &[usize; 3]) -> i64 {
+/// sum of the coordinates of the rock's start, none if the three hailstones do not determine it
+fn solve(projectiles: &[Projectile], idzs: &[usize; 3]) -> Option<i64> {
     let projectiles: Vec<Vec<[f64; 3]>> = vec![
         vec![projectiles[idzs[0]].start, projectiles[idzs[0]].velocity],
         vec![projectiles[idzs[1]].start, projectiles[idzs[1]].velocity],
@@ -166,20 +189,46 @@ fn solve(projectiles: &[Projectile], idzs: &[usize; 3]) -> i64 {
     let mat = Matrix3::from_rows(&[row1, row2, row3]);
     let data = Vector3::from_row_slice(&b);
 
-    let xyz = mat.lu().solve(&data);
-    xyz.unwrap().sum().ceil() as i64
+    let xyz = mat.lu().solve(&data)?;
+    Some(xyz.sum().ceil() as i64)
 }
 
 // part two is basically taken from https://github.com/apprenticewiz/adventofcode/blob/main/2023/rust/day24b/src/main.rs
 // with addition of checking for consistent solutions
-pub fn part2(input: &str) -> i64 {
-    let projectiles = parse_input2(input);
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let projectiles = puzzle.projectiles.as_ref().map_err(Clone::clone)?;
+    let solve = |idzs: [usize; 3]| {
+        solve(projectiles, &idzs).ok_or_else(|| {
+            SolveError::unsolvable(format!("the hailstones {idzs:?} do not determine the rock"))
+        })
+    };
     let idzs: Vec<[usize; 3]> = vec![[1, 2, 5], [2, 3, 4]];
     // [0, 1, 2 somehow gives a wrong result, probably due to numeric issues]
-    let sol = solve(&projectiles, &[1, 2, 3]);
+    let sol = solve([1, 2, 3])?;
     for idx in idzs {
-        let new_sol = solve(&projectiles, &idx);
-        assert_eq!(sol, new_sol);
+        let new_sol = solve(idx)?;
+        if new_sol != sol {
+            return Err(SolveError::unsolvable(format!(
+                "the hailstones {idx:?} give {new_sol} instead of {sol}"
+            )));
+        }
+    }
+    Ok(sol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_parse_errors() {
+        let puzzle = parse("19, 13, 30 @ -2, 1, -2\n18, 19 @ -1, -1, -2\n").unwrap();
+        let Err(SolveError::Input(err)) = part1(&puzzle) else {
+            panic!("expected an input error");
+        };
+        assert_eq!(err.location.map(|location| location.line), Some(2));
+        let Err(SolveError::Input(err)) = part2(&puzzle) else {
+            panic!("expected an input error");
+        };
+        assert_eq!(err.location.map(|location| location.line), Some(2));
     }
-    sol
 }
@@ -1,18 +1,21 @@
 use crate::util::*;
 use nalgebra::DVector;
 use std::collections::HashSet;
-fn parse_input(input: &str) -> Graph<String> {
+/// the wiring diagram of the components
+pub type Puzzle = Graph<String>;
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
     let data: Vec<(String, Vec<String>)> = input
         .lines()
-        .filter_map(|line| {
-            line.split_once(": ").map(|(name, childs)| {
+        .map(|line| {
+            split_once(line, ": ").map(|(name, childs)| {
                 (
                     name.to_owned(),
                     childs.split(' ').map(|s| s.to_owned()).collect(),
                 )
             })
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
     let mut graph = Graph::new();
     for (name, childs) in data {
         graph.add_node(name.clone());
@@ -22,11 +25,10 @@ fn parse_input(input: &str) -> Graph<String> {
             graph.add_edge(&child, &name, 1);
         }
     }
-    graph
+    Ok(graph)
 }
 
-pub fn part1(input: &str) -> i64 {
-    let graph = parse_input(input);
+pub fn part1(graph: &Puzzle) -> Result<i64, SolveError> {
     let deg = graph.degree_matrix();
     let adj = graph.adjacency_matrix();
     let lap = (deg - adj).cast::<f64>();
@@ -40,7 +42,10 @@ pub fn part1(input: &str) -> i64 {
         .map(|(a, b)| (*a, b.into_owned()))
         .collect();
     eigenvecs.sort_by(|a, b| a.0.total_cmp(&b.0));
-    let v2 = &eigenvecs[1].1;
+    let v2 = &eigenvecs
+        .get(1)
+        .ok_or_else(|| SolveError::unsolvable("expected at least two components"))?
+        .1;
     let grpa: HashSet<usize> = v2
         .iter()
         .enumerate()
@@ -56,17 +61,23 @@ pub fn part1(input: &str) -> i64 {
     let mut cuts = HashSet::new();
     for a in &grpa {
         for b in &grpb {
-            if graph.edges[*a].get(b).is_some() {
+            if graph.edges[*a].contains_key(b) {
                 cuts.insert((a, b));
             }
         }
     }
     // make sure we do 3 cuts
-    assert_eq!(cuts.len(), 3);
-    (grpa.len() * grpb.len()) as i64
+    if cuts.len() != 3 {
+        return Err(SolveError::unsolvable(format!(
+            "expected the components to split into two groups connected by 3 wires, found {}",
+            cuts.len()
+        )));
+    }
+    Ok((grpa.len() * grpb.len()) as i64)
 }
 
-pub fn part2(_input: &str) -> i64 {
-    println!("done!");
-    0
+pub fn part2(_puzzle: &Puzzle) -> Result<i64, SolveError> {
+    Err(SolveError::unsolvable(
+        "there is no puzzle for the second part of the last day",
+    ))
 }
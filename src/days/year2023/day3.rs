@@ -0,0 +1,132 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::util::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cell {
+    Empty,
+    Digit(u8),
+    Symbol(char),
+}
+impl FromChar for Cell {
+    fn default() -> Self {
+        Self::Empty
+    }
+    fn from_char(char: &char) -> Option<Self> {
+        match char {
+            '.' => Some(Self::Empty),
+            '0'..='9' => char.to_digit(10).map(|digit| Self::Digit(digit as u8)),
+            char => Some(Self::Symbol(*char)),
+        }
+    }
+}
+impl ToChar for Cell {
+    fn to_char(&self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::Digit(digit) => (b'0' + digit) as char,
+            Self::Symbol(char) => *char,
+        }
+    }
+}
+impl MatrixElement for Cell {}
+
+/// the engine schematic
+pub type Puzzle = Matrix<Cell>;
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    Matrix::from_string(input)
+}
+
+/// every cell of the schematic row by row with its index
+fn cells(schematic: &Matrix<Cell>) -> impl Iterator<Item = (MatrixIdx, &Cell)> {
+    schematic.rows().enumerate().flat_map(|(row, line)| {
+        line.iter().enumerate().map(move |(col, cell)| {
+            let idx = MatrixIdx {
+                row: row as i64,
+                col: col as i64,
+            };
+            (idx, cell)
+        })
+    })
+}
+
+pub fn part1(schematic: &Puzzle) -> Result<i64, SolveError> {
+    let valid: HashSet<MatrixIdx> = schematic
+        .findall(|cell| matches!(cell, Cell::Symbol(_)))
+        .iter()
+        .flat_map(|idx| schematic.neighbours(idx, Neighbourhood::Eight))
+        .collect();
+
+    let mut digit = 0;
+    let mut is_valid = false;
+    let mut sum = 0;
+    for (idx, cell) in cells(schematic) {
+        if let Cell::Digit(value) = cell {
+            digit = digit * 10 + *value as i32;
+            if valid.contains(&idx) {
+                is_valid = true
+            }
+        } else {
+            if is_valid {
+                sum += digit;
+            }
+
+            is_valid = false;
+            digit = 0;
+        }
+    }
+    Ok(sum as i64)
+}
+pub fn part2(schematic: &Puzzle) -> Result<i64, SolveError> {
+    let mut innumber = false;
+    let mut number = 0;
+    let mut numberidx = 0usize;
+    let mut numbers = vec![];
+    let mut numbermap = HashMap::new();
+
+    for (idx, cell) in cells(schematic) {
+        if let Cell::Digit(value) = cell {
+            innumber = true;
+            number = number * 10 + *value as i32;
+            numbermap.insert(idx, numberidx);
+        } else {
+            if innumber {
+                numbers.push(number);
+                numberidx += 1;
+            }
+            number = 0;
+            innumber = false;
+        }
+    }
+    let mut sum = 0;
+    for gear_index in schematic.findall(|cell| cell == &Cell::Symbol('*')) {
+        let mut neighbor_numbers = HashSet::new();
+        for idx in schematic.neighbours(&gear_index, Neighbourhood::Eight) {
+            if let Some(numberidx) = numbermap.get(&idx) {
+                neighbor_numbers.insert(numbers[*numberidx] as i64);
+            }
+        }
+
+        if neighbor_numbers.len() == 2 {
+            let solution = neighbor_numbers.iter().product::<i64>();
+            sum += solution
+        }
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_neighbor_idzs() {
+        let matrix = Matrix::filled(10, 10, Cell::Empty);
+        let center = MatrixIdx { row: 4, col: 2 };
+        let neighbors: Vec<i64> = matrix
+            .neighbours(&center, Neighbourhood::Eight)
+            .map(|idx| idx.row * 10 + idx.col)
+            .collect();
+        assert_eq!(vec![31, 32, 33, 41, 43, 51, 52, 53], neighbors)
+    }
+}
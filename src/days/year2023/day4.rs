@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+
+use crate::util::{parse_num, split_once, ParseError, SolveError};
+
+fn parse_numbers(input: &str) -> Result<HashSet<i32>, ParseError> {
+    let iter = input.split(' ');
+    let mut numbers = HashSet::new();
+    for numstr in iter {
+        if numstr.is_empty() {
+            continue;
+        }
+        numbers.insert(parse_num::<i32>(numstr)?);
+    }
+    Ok(numbers)
+}
+fn count_hits(game: &str) -> Result<usize, ParseError> {
+    let (_, temp) = split_once(game, ":")?;
+    let (winning, numbers) = split_once(temp, "|")?;
+    let winning = parse_numbers(winning)?;
+    let numbers = parse_numbers(numbers)?;
+    Ok(winning.intersection(&numbers).count())
+}
+/// number of winning numbers on each card
+pub type Puzzle = Vec<usize>;
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    input.lines().map(count_hits).collect()
+}
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let mut sum = 0;
+    for &hits in puzzle {
+        if hits > 0 {
+            let add = i32::pow(2, (hits - 1) as u32);
+            sum += add;
+        }
+    }
+    Ok(sum as i64)
+}
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let ncards = puzzle.len();
+    let mut carddeck = vec![1; ncards];
+    for (cardidx, &hits) in puzzle.iter().enumerate() {
+        let stop = std::cmp::min(ncards - 1, cardidx + hits);
+        let cardcnt = carddeck[cardidx];
+        for card in carddeck.iter_mut().take(stop + 1).skip(cardidx + 1) {
+            *card += cardcnt;
+        }
+    }
+
+    let sum = carddeck.iter().sum::<i32>();
+    Ok(sum as i64)
+}
+
+#[cfg(test)]
+mod tests {}
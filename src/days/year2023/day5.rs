@@ -1,4 +1,4 @@
-use std::{time::Instant, vec};
+use crate::util::{next_token, parse_num, ParseError, SolveError};
 
 #[derive(Debug, Clone)]
 struct Range {
@@ -67,12 +67,18 @@ struct RangeMap {
     length: i64,
 }
 impl RangeMap {
-    fn from_line(line: &str) -> Self {
-        let data = iter2i64(line.split(' '));
+    fn from_line(line: &str) -> Result<Self, ParseError> {
+        let data = iter2i64(line.split(' '))?;
+        if data.len() != 3 {
+            return Err(ParseError::at(
+                line,
+                "expected destination, source and length",
+            ));
+        }
         let src = data[1];
         let dst = data[0];
         let length = data[2];
-        Self { dst, src, length }
+        Ok(Self { dst, src, length })
     }
     fn offset(&self) -> i64 {
         self.dst - self.src
@@ -156,24 +162,38 @@ impl Map {
     }
 }
 
-fn iter2i64<'a>(seed_iter: impl Iterator<Item = &'a str>) -> Vec<i64> {
+fn iter2i64<'a>(seed_iter: impl Iterator<Item = &'a str>) -> Result<Vec<i64>, ParseError> {
     let mut numbers = vec![];
     for seedstr in seed_iter {
-        let seed = seedstr.to_string().parse::<i64>().unwrap();
+        let seed = parse_num::<i64>(seedstr)?;
         numbers.push(seed);
     }
-    numbers
+    Ok(numbers)
 }
-fn parse_map<'a>(mut lines: impl Iterator<Item = &'a str>, start_marker: &str) -> Map {
-    lines.find(|s| *s == start_marker);
+fn parse_map<'a>(
+    mut lines: impl Iterator<Item = &'a str>,
+    start_marker: &str,
+) -> Result<Map, ParseError> {
+    if !lines.any(|s| s == start_marker) {
+        return Err(ParseError::new(format!("missing '{start_marker}'")));
+    }
     let mut maps = vec![];
     for line in lines {
         if line.is_empty() {
             break;
         }
-        maps.push(RangeMap::from_line(line));
+        maps.push(RangeMap::from_line(line)?);
     }
-    Map { maps }
+    Ok(Map { maps })
+}
+fn parse_seeds<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+    input: &'a str,
+) -> Result<Vec<i64>, ParseError> {
+    let line = next_token(lines, input, "seeds")?;
+    let mut seed_iter = line.split(' ');
+    seed_iter.next();
+    iter2i64(seed_iter)
 }
 const MARKERS: [&str; 7] = [
     "seed-to-soil map:",
@@ -184,38 +204,45 @@ const MARKERS: [&str; 7] = [
     "temperature-to-humidity map:",
     "humidity-to-location map:",
 ];
-pub fn part1(input: &str) -> i64 {
+/// the seeds and the chain of maps from seed to location
+pub struct Puzzle {
+    seeds: Vec<i64>,
+    map_chain: Vec<Map>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
     let mut lines = input.lines();
 
-    let mut seed_iter = lines.next().unwrap().split(' ');
-    seed_iter.next();
-    let seeds = iter2i64(seed_iter);
+    let seeds = parse_seeds(&mut lines, input)?;
 
     let mut map_chain = vec![];
     for marker in MARKERS.iter() {
-        let map = parse_map(&mut lines, marker);
+        let map = parse_map(&mut lines, marker)?;
         map_chain.push(map);
     }
-
+    Ok(Puzzle { seeds, map_chain })
+}
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
     let mut results = vec![];
-    for seed in seeds.iter() {
+    for seed in puzzle.seeds.iter() {
         let mut maped = seed.to_owned();
-        for map in map_chain.iter() {
+        for map in puzzle.map_chain.iter() {
             maped = map.apply(maped);
         }
         results.push(maped)
     }
-    let solution = results.iter().min().unwrap();
+    let solution = results
+        .iter()
+        .min()
+        .ok_or_else(|| ParseError::on_line(1, "no seeds given"))?;
 
-    *solution
+    Ok(*solution)
 }
-pub fn part2(input: &str) -> i64 {
-    let _start = Instant::now();
-    let mut lines = input.lines();
-
-    let mut seed_iter = lines.next().unwrap().split(' ');
-    seed_iter.next();
-    let seeds = iter2i64(seed_iter);
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let seeds = &puzzle.seeds;
+    if seeds.len() % 2 != 0 {
+        return Err(ParseError::on_line(1, "expected pairs of seed start and length").into());
+    }
 
     let mut seed_ranges = vec![];
     for i in (0..seeds.len()).step_by(2) {
@@ -225,23 +252,21 @@ pub fn part2(input: &str) -> i64 {
         })
     }
 
-    let mut map_chain = vec![];
-    for marker in MARKERS.iter() {
-        let map = parse_map(&mut lines, marker);
-        map_chain.push(map);
-    }
-
     let mut ranges = seed_ranges;
 
-    for map in map_chain.iter() {
+    for map in puzzle.map_chain.iter() {
         // &map);
         ranges = map.apply2ranges(ranges);
         // &ranges);
     }
 
-    let solution = ranges.iter().min_by_key(|r| r.start).unwrap().start;
+    let solution = ranges
+        .iter()
+        .min_by_key(|r| r.start)
+        .ok_or_else(|| ParseError::on_line(1, "no seeds given"))?
+        .start;
 
-    solution
+    Ok(solution)
 }
 
 #[cfg(test)]
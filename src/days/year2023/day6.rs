@@ -1,11 +1,13 @@
-fn parse_line(line: &str) -> Vec<i64> {
+use crate::util::{next_token, parse_num, ParseError, SolveError};
+
+fn parse_line(line: &str) -> Result<Vec<i64>, ParseError> {
     let mut numbers = vec![];
     let mut iter = line.split_whitespace();
     iter.next();
     for num in iter {
-        numbers.push(num.to_string().parse::<i64>().unwrap());
+        numbers.push(parse_num::<i64>(num)?);
     }
-    numbers
+    Ok(numbers)
 }
 
 fn compute_limits(time: i64, distance: i64) -> (f64, f64) {
@@ -19,10 +21,21 @@ fn compute_limits(time: i64, distance: i64) -> (f64, f64) {
     (t / 2., (t.powi(2) / 4. - d).sqrt())
 }
 
-fn parse_races(input: &str) -> Vec<(i64, i64)> {
-    let times = parse_line(input.lines().next().unwrap());
-    let distance = parse_line(input.lines().nth(1).unwrap());
-    times.into_iter().zip(distance).collect()
+/// time and record distance of each race
+pub type Puzzle = Vec<(i64, i64)>;
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let mut lines = input.lines();
+    let times = parse_line(next_token(&mut lines, input, "times")?)?;
+    let distance_line = next_token(&mut lines, input, "distances")?;
+    let distance = parse_line(distance_line)?;
+    if times.len() != distance.len() {
+        return Err(ParseError::at(
+            distance_line,
+            format!("expected {} distances", times.len()),
+        ));
+    }
+    Ok(times.into_iter().zip(distance).collect())
 }
 
 fn compute_solution_count((time, distance): (i64, i64)) -> i64 {
@@ -32,24 +45,20 @@ fn compute_solution_count((time, distance): (i64, i64)) -> i64 {
     maxt - mint + 1
 }
 
-pub fn part1(input: &str) -> i64 {
-    let races = parse_races(input);
-
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
     let mut solution = 1;
-    for race in races {
+    for &race in puzzle {
         let count = compute_solution_count(race);
         solution *= count;
     }
-    solution
+    Ok(solution)
 }
-pub fn part2(input: &str) -> i64 {
-    let races = parse_races(input);
-
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
     let mut solution = 1;
-    for race in races {
+    for &race in puzzle {
         let count = compute_solution_count(race);
         solution *= count;
     }
 
-    solution
+    Ok(solution)
 }
@@ -3,6 +3,10 @@ use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 
+use crate::util::{next_token, parse_num, ParseError, SolveError};
+
+const CARDS: &str = "AKQJT98765432";
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 enum HandType {
     FiveOfAKind = 6,
@@ -172,20 +176,8 @@ where
     T: Card,
     Vec<T>: CardCount,
 {
-    fn parse(line: &str) -> Self {
-        let cards: Vec<T> = line
-            .split_whitespace()
-            .next()
-            .unwrap()
-            .chars()
-            .map(|char| T::from_char(char))
-            .collect();
-        let bid = line
-            .split_whitespace()
-            .nth(1)
-            .unwrap()
-            .parse::<i64>()
-            .unwrap();
+    fn new(cards: &str, bid: i64) -> Self {
+        let cards: Vec<T> = cards.chars().map(|char| T::from_char(char)).collect();
         Hand { cards, bid }
     }
 
@@ -229,22 +221,56 @@ where
     }
 }
 
-pub fn part1(input: &str) -> i64 {
-    let mut hands: Vec<Hand<CardN>> = input.lines().map(Hand::parse).collect();
+fn parse_hand(line: &str) -> Result<(String, i64), ParseError> {
+    let mut tokens = line.split_whitespace();
+    let cards_str = next_token(&mut tokens, line, "cards")?;
+    if let Some((idx, char)) = cards_str.char_indices().find(|(_, c)| !CARDS.contains(*c)) {
+        return Err(ParseError::at(
+            &cards_str[idx..],
+            format!("invalid card '{char}'"),
+        ));
+    }
+    if cards_str.len() != 5 {
+        return Err(ParseError::at(cards_str, "expected a hand of 5 cards"));
+    }
+    let bid = parse_num::<i64>(next_token(&mut tokens, line, "bid")?)?;
+    Ok((cards_str.to_owned(), bid))
+}
+
+/// cards and bid of every hand, the parts rank the cards differently
+pub struct Puzzle {
+    hands: Vec<(String, i64)>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let hands = input.lines().map(parse_hand).collect::<Result<_, _>>()?;
+    Ok(Puzzle { hands })
+}
+
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let mut hands: Vec<Hand<CardN>> = puzzle
+        .hands
+        .iter()
+        .map(|(cards, bid)| Hand::new(cards, *bid))
+        .collect();
     hands.sort();
     let mut solution = 0;
     for (rank, hand) in hands.iter().enumerate() {
         // &hand;
         solution += hand.bid * (rank as i64 + 1)
     }
-    solution
+    Ok(solution)
 }
-pub fn part2(input: &str) -> i64 {
-    let mut hands: Vec<Hand<CardJ>> = input.lines().map(Hand::parse).collect();
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let mut hands: Vec<Hand<CardJ>> = puzzle
+        .hands
+        .iter()
+        .map(|(cards, bid)| Hand::new(cards, *bid))
+        .collect();
     hands.sort();
     let mut solution = 0;
     for (rank, hand) in hands.iter().enumerate() {
         solution += hand.bid * (rank as i64 + 1)
     }
-    solution
+    Ok(solution)
 }
@@ -1,5 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::util::{next_token, split_once, ParseError, SolveError};
+
 type Node = [char; 3];
 #[derive(Debug, Clone)]
 enum Direction {
@@ -8,73 +10,98 @@ enum Direction {
 }
 
 impl Direction {
-    fn from_char(char: char) -> Direction {
+    fn from_char(char: char) -> Option<Direction> {
         match char {
-            'L' => Self::Left,
-            'R' => Self::Right,
-            _ => panic!("should not get here"),
+            'L' => Some(Self::Left),
+            'R' => Some(Self::Right),
+            _ => None,
         }
     }
 }
 
 trait MNode {
-    fn from_string(string: &str) -> Node;
+    fn from_string(string: &str) -> Result<Node, ParseError>;
 }
 impl MNode for Node {
-    fn from_string(string: &str) -> Node {
+    fn from_string(string: &str) -> Result<Node, ParseError> {
         let temp: Vec<char> = string.chars().collect();
+        if temp.len() != 3 {
+            return Err(ParseError::at(
+                string,
+                format!("invalid node '{string}', expected 3 characters"),
+            ));
+        }
         let mut name: [char; 3] = ['0'; 3];
         name[..3].copy_from_slice(&temp[..3]);
-        name
+        Ok(name)
     }
 }
 
 #[derive(Debug)]
-struct Puzzle {
+pub struct Puzzle {
     directions: Vec<Direction>,
     nodes: HashSet<Node>,
     graph: HashMap<Node, [Node; 2]>,
 }
-fn parse_line(line: &str) -> (Node, [Node; 2]) {
-    let mut split = line.split(" = ");
-    let node = Node::from_string(split.next().unwrap());
-    let mut children = split.next().unwrap().to_owned();
+fn parse_line(line: &str) -> Result<(Node, [Node; 2]), ParseError> {
+    let (node, children) = split_once(line, " = ")?;
+    let node = Node::from_string(node)?;
 
-    children = children.replace('(', "");
-    children = children.replace(')', "");
+    let children = children.trim_start_matches('(').trim_end_matches(')');
     let mut childrens = children.split(", ");
-    let left = Node::from_string(childrens.next().unwrap());
-    let right = Node::from_string(childrens.next().unwrap());
-    (node, [left, right])
+    let left = Node::from_string(next_token(&mut childrens, line, "left node")?)?;
+    let right = Node::from_string(next_token(&mut childrens, line, "right node")?)?;
+    Ok((node, [left, right]))
 }
 
 impl Puzzle {
-    fn parse(input: &str) -> Puzzle {
+    fn parse(input: &str) -> Result<Puzzle, ParseError> {
         let mut lines = input.lines();
 
-        let directions: Vec<Direction> = lines
-            .next()
-            .unwrap()
-            .chars()
-            .map(Direction::from_char)
-            .collect();
+        let direction_line = next_token(&mut lines, input, "directions")?;
+        let directions: Vec<Direction> = direction_line
+            .char_indices()
+            .map(|(idx, char)| {
+                Direction::from_char(char).ok_or_else(|| {
+                    ParseError::at(
+                        &direction_line[idx..],
+                        format!("invalid direction '{char}'"),
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        if directions.is_empty() {
+            return Err(ParseError::at(direction_line, "no directions given"));
+        }
 
         lines.next();
         let mut nodes = HashSet::new();
         let mut graph = HashMap::new();
+        let mut children = Vec::new();
         for line in lines {
-            let (node, [left, right]) = parse_line(line);
+            let (node, [left, right]) = parse_line(line)?;
             nodes.insert(node);
             nodes.insert(left);
             nodes.insert(right);
             graph.insert(node, [left, right]);
+            children.push((line, [left, right]));
+        }
+        // every node we can walk to needs its own line, otherwise the traversal gets stuck
+        for (line, pair) in children {
+            if let Some(child) = pair.iter().find(|child| !graph.contains_key(*child)) {
+                let name: String = child.iter().collect();
+                return Err(ParseError::at(
+                    line,
+                    format!("node '{name}' is never defined"),
+                ));
+            }
         }
 
-        Puzzle {
+        Ok(Puzzle {
             directions,
             nodes,
             graph,
-        }
+        })
     }
     fn traverse(&self, start: &Node, stop: &Node) -> usize {
         let ndir = self.directions.len();
@@ -107,9 +134,12 @@ impl Puzzle {
             Direction::Right => self.get(&children[1]),
         }
     }
-    fn get_node(&self, name: &str) -> Option<&Node> {
-        let node = Node::from_string(name);
-        self.nodes.get(&node)
+    fn get_node(&self, name: &str) -> Result<&Node, ParseError> {
+        Node::from_string(name).and_then(|node| {
+            self.nodes
+                .get(&node)
+                .ok_or_else(|| ParseError::new(format!("missing node '{name}'")))
+        })
     }
     fn get(&self, node: &Node) -> Option<&Node> {
         self.nodes.get(node)
@@ -153,25 +183,25 @@ fn gcd(first: usize, second: usize) -> usize {
     }
 }
 
-pub fn part1(input: &str) -> i64 {
-    let puzzle = Puzzle::parse(input);
-
-    let start = puzzle.get_node("AAA").unwrap();
-    let stop = puzzle.get_node("ZZZ").unwrap();
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    Puzzle::parse(input)
+}
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
+    let start = puzzle.get_node("AAA")?;
+    let stop = puzzle.get_node("ZZZ")?;
     let solution = puzzle.traverse(start, stop);
-    solution as i64
+    Ok(solution as i64)
 }
-pub fn part2(input: &str) -> i64 {
-    let puzzle = Puzzle::parse(input);
-
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
     let starts = puzzle.starting_nodes();
 
     let steps: Vec<usize> = starts.iter().map(|start| puzzle.traverse2(start)).collect();
+    log::debug!("cycle lengths of the {} ghosts: {steps:?}", steps.len());
 
     let mut solution = 1;
     for step in steps {
         solution = lcm(solution, step)
     }
 
-    solution as i64
+    Ok(solution as i64)
 }
@@ -1,10 +1,30 @@
-pub fn part1(input: &str) -> i64 {
+use crate::util::{parse_num, ParseError, SolveError};
+
+fn parse_line(line: &str) -> Result<Vec<i64>, ParseError> {
+    let numbers: Vec<i64> = line
+        .split_whitespace()
+        .map(parse_num::<i64>)
+        .collect::<Result<_, _>>()?;
+    if numbers.len() < 2 {
+        return Err(ParseError::at(
+            line,
+            "expected a history of at least 2 values",
+        ));
+    }
+    Ok(numbers)
+}
+
+/// the history of every value
+pub type Puzzle = Vec<Vec<i64>>;
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    input.lines().map(parse_line).collect()
+}
+
+pub fn part1(puzzle: &Puzzle) -> Result<i64, SolveError> {
     let mut solution = 0;
-    for line in input.lines() {
-        let numbers: Vec<i64> = line
-            .split_whitespace()
-            .map(|s| s.parse::<i64>().unwrap())
-            .collect();
+    for numbers in puzzle {
+        let numbers = numbers.clone();
 
         let mut number_stack = vec![numbers.clone()];
         let mut current = numbers;
@@ -23,15 +43,12 @@ pub fn part1(input: &str) -> i64 {
 
         solution += diff;
     }
-    solution
+    Ok(solution)
 }
-pub fn part2(input: &str) -> i64 {
+pub fn part2(puzzle: &Puzzle) -> Result<i64, SolveError> {
     let mut solution = 0;
-    for line in input.lines() {
-        let numbers: Vec<i64> = line
-            .split_whitespace()
-            .map(|s| s.parse::<i64>().unwrap())
-            .collect();
+    for numbers in puzzle {
+        let numbers = numbers.clone();
         let mut number_stack = vec![numbers.clone()];
         let mut current = numbers;
         let mut done = false;
@@ -49,5 +66,5 @@ pub fn part2(input: &str) -> i64 {
         //
         solution += diff;
     }
-    solution
+    Ok(solution)
 }
@@ -0,0 +1,228 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use toml_edit::{value, ArrayOfTables, Item, Table};
+
+use crate::remote::Reply;
+use crate::toml_file::{table_entry, FileError, TomlFile};
+use crate::util::Answer;
+
+/// a single submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub answer: Answer,
+    pub reply: Reply,
+    /// seconds since the unix epoch
+    pub timestamp: u64,
+}
+
+/// reason for not submitting an answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(Answer),
+    Rejected(Answer, Option<String>),
+    /// seconds left until the next answer may be submitted
+    Cooldown(u64),
+}
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => write!(f, "already solved with {answer}"),
+            Refusal::Rejected(answer, None) => write!(f, "{answer} was already rejected"),
+            Refusal::Rejected(answer, Some(hint)) => {
+                write!(f, "{answer} was already rejected as {hint}")
+            }
+            Refusal::Cooldown(seconds) => {
+                write!(
+                    f,
+                    "an answer was submitted recently, wait another {seconds}s"
+                )
+            }
+        }
+    }
+}
+
+/// every answer submitted for a season, stored as toml keyed by day and part
+///
+/// ```toml
+/// [[day1.part1]]
+/// answer = 55447
+/// reply = "correct"
+/// timestamp = 1701406800
+/// ```
+///
+/// rejected answers also store the `hint`, replies asking to wait the `wait_s` given by the website
+pub struct History {
+    file: TomlFile,
+}
+impl History {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, FileError> {
+        TomlFile::load(path).map(|file| Self { file })
+    }
+    pub fn path(&self) -> &Path {
+        self.file.path()
+    }
+    pub fn attempts(&self, day: i32, part: i32) -> Vec<Attempt> {
+        self.file
+            .document()
+            .get(&format!("day{day}"))
+            .and_then(|day| day.get(format!("part{part}")))
+            .map_or(vec![], attempts)
+    }
+    /// checks whether `answer` may be submitted at `now`, answers are only submitted once, never after
+    /// the part is solved and not before `cooldown` seconds passed since the last submission of the
+    /// season or the wait time the website asked for
+    pub fn check(
+        &self,
+        day: i32,
+        part: i32,
+        answer: &Answer,
+        now: u64,
+        cooldown: u64,
+    ) -> Result<(), Refusal> {
+        for attempt in self.attempts(day, part) {
+            match attempt.reply {
+                Reply::Correct => return Err(Refusal::AlreadySolved(attempt.answer)),
+                Reply::Wrong(hint) if &attempt.answer == answer => {
+                    return Err(Refusal::Rejected(attempt.answer, hint))
+                }
+                _ => {}
+            }
+        }
+        let ready = self
+            .file
+            .document()
+            .iter()
+            .filter_map(|(_, day)| day.as_table_like())
+            .flat_map(|parts| parts.iter().flat_map(|(_, part)| attempts(part)))
+            .map(|attempt| match attempt.reply {
+                Reply::TooRecent(Some(wait)) => attempt.timestamp + wait.max(cooldown),
+                _ => attempt.timestamp + cooldown,
+            })
+            .max();
+        match ready {
+            Some(ready) if ready > now => Err(Refusal::Cooldown(ready - now)),
+            _ => Ok(()),
+        }
+    }
+    pub fn record(&mut self, day: i32, part: i32, attempt: &Attempt) {
+        let day = table_entry(self.file.document_mut(), &format!("day{day}"));
+        day.set_implicit(true);
+        let part = day
+            .entry(&format!("part{part}"))
+            .or_insert(Item::ArrayOfTables(ArrayOfTables::new()));
+        if !part.is_array_of_tables() {
+            *part = Item::ArrayOfTables(ArrayOfTables::new());
+        }
+        let mut table = Table::new();
+        table["answer"] = match &attempt.answer {
+            Answer::Integer(number) => value(*number),
+            answer => value(answer.to_string()),
+        };
+        table["reply"] = value(attempt.reply.to_string());
+        table["timestamp"] = value(attempt.timestamp as i64);
+        match &attempt.reply {
+            Reply::Wrong(Some(hint)) => table["hint"] = value(hint),
+            Reply::TooRecent(Some(wait)) => table["wait_s"] = value(*wait as i64),
+            _ => {}
+        }
+        part.as_array_of_tables_mut().unwrap().push(table);
+    }
+    pub fn save(&self) -> Result<(), FileError> {
+        self.file.save()
+    }
+}
+
+fn attempts(part: &Item) -> Vec<Attempt> {
+    let Some(tables) = part.as_array_of_tables() else {
+        return vec![];
+    };
+    tables
+        .iter()
+        .filter_map(|table| {
+            let answer = table.get("answer")?;
+            let answer = match answer.as_integer() {
+                Some(number) => Answer::Integer(number),
+                None => answer.as_str()?.parse().ok()?,
+            };
+            let hint = table.get("hint").and_then(Item::as_str).map(str::to_owned);
+            let wait = table
+                .get("wait_s")
+                .and_then(Item::as_integer)
+                .map(|wait| wait as u64);
+            let reply = match table.get("reply")?.as_str()? {
+                "correct" => Reply::Correct,
+                "wrong" => Reply::Wrong(hint),
+                "too_recent" => Reply::TooRecent(wait),
+                "wrong_level" => Reply::WrongLevel,
+                _ => Reply::Unknown,
+            };
+            let timestamp = table.get("timestamp")?.as_integer()? as u64;
+            Some(Attempt {
+                answer,
+                reply,
+                timestamp,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_check() {
+        let mut history = History {
+            file: TomlFile::default(),
+        };
+        let wrong = Attempt {
+            answer: Answer::from(100),
+            reply: Reply::Wrong(Some("too low".to_owned())),
+            timestamp: 1000,
+        };
+        history.record(1, 1, &wrong);
+        assert_eq!(
+            history.file.document().to_string(),
+            "[[day1.part1]]\nanswer = 100\nreply = \"wrong\"\ntimestamp = 1000\nhint = \"too low\"\n"
+        );
+        assert_eq!(history.attempts(1, 1), vec![wrong]);
+        assert_eq!(
+            history.check(1, 1, &Answer::from(100), 2000, 60),
+            Err(Refusal::Rejected(
+                Answer::from(100),
+                Some("too low".to_owned())
+            ))
+        );
+        assert_eq!(
+            history.check(1, 1, &Answer::from(200), 1030, 60),
+            Err(Refusal::Cooldown(30))
+        );
+        assert_eq!(history.check(1, 1, &Answer::from(200), 1060, 60), Ok(()));
+        history.record(
+            2,
+            1,
+            &Attempt {
+                answer: Answer::from(5),
+                reply: Reply::TooRecent(Some(300)),
+                timestamp: 1100,
+            },
+        );
+        assert_eq!(
+            history.check(1, 1, &Answer::from(200), 1200, 60),
+            Err(Refusal::Cooldown(200))
+        );
+        history.record(
+            1,
+            1,
+            &Attempt {
+                answer: Answer::from(200),
+                reply: Reply::Correct,
+                timestamp: 1400,
+            },
+        );
+        assert_eq!(
+            history.check(1, 1, &Answer::from(300), 2000, 60),
+            Err(Refusal::AlreadySolved(Answer::from(200)))
+        );
+    }
+}
@@ -1,2 +1,12 @@
+pub mod answers;
 pub mod days;
+pub mod history;
+pub mod memory;
+pub mod output;
+pub mod remote;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod timing;
+pub mod toml_file;
 pub mod util;
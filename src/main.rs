@@ -1,16 +1,69 @@
-mod days;
-mod util;
-use std::time::Instant;
+use std::any::Any;
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 
-use clap::{arg, Parser};
+use adventofcode::answers::{AnswerFile, Dataset, Verdict};
+use adventofcode::days::{
+    check_selection, find_solver, latest_year, Puzzle, Solver, SolverError, DAYS, SOLVERS,
+};
+use adventofcode::history::{Attempt, History};
+use adventofcode::memory::{self, CountingAllocator};
+use adventofcode::output::{format_bytes, Format, Printer, Record, Status};
+use adventofcode::remote::{Client, Config, Reply};
+use adventofcode::report::{self, Report};
+use adventofcode::runner::{self, guarded, Snapshot};
+use adventofcode::scaffold;
+use adventofcode::timing::{self, Baseline, Timing};
+use adventofcode::toml_file::FileError;
+use adventofcode::util::{self, Answer, InputError, Location, SolveError};
+use clap::{ArgAction, Parser, Subcommand};
 
-use crate::days::dispatch_function;
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// prints the log messages of the library to stderr, so they do not mix with the results
+struct StderrLogger;
+impl log::Log for StderrLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+    fn flush(&self) {}
+}
+static LOGGER: StderrLogger = StderrLogger;
 
 /// Solving adventofcode challenges
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Cli {
-    day: i32,
-    part: i32,
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[arg(
+        value_parser = parse_days,
+        required = true,
+        help = "day to run, either a single day, a range like 5..=12 or 'all'"
+    )]
+    day: Option<RangeInclusive<i32>>,
+    #[arg(help = "part to run, runs all parts if omitted")]
+    part: Option<i32>,
+    #[arg(
+        short = 'y',
+        long = "year",
+        global = true,
+        default_value_t = latest_year(),
+        help = "year of the advent calendar, defaults to the latest one with implemented solvers"
+    )]
+    year: i32,
     // use short test dataset
     #[arg(
         short = 't',
@@ -22,50 +75,1161 @@ struct Cli {
     #[arg(
         short = 'd',
         long = "data",
+        global = true,
         default_value = "data",
-        help = "path to the input data folder"
+        help = "path to the input data folder, holding one folder per year"
     )]
     data: String,
+    #[arg(
+        short = 'i',
+        long = "input",
+        value_name = "PATH",
+        conflicts_with_all = ["verify", "record"],
+        help = "read the input from a file instead of the data folder, '-' reads stdin"
+    )]
+    input: Option<String>,
     #[arg(
         short = 'p',
         long = "profile",
         default_value = "false",
-        help = "repeat runs x1000 for profiling"
+        help = "repeat runs and report timing statistics"
     )]
     profile: bool,
     #[arg(
         short = 'n',
         long = "numruns",
-        default_value = "1000",
-        help = "how often to repat the function call for profiling"
+        default_value = "100",
+        help = "how often to repeat the function call for profiling"
+    )]
+    numruns: usize,
+    #[arg(
+        long = "warmup",
+        default_value = "10",
+        help = "unmeasured runs before profiling"
+    )]
+    warmup: usize,
+    #[arg(
+        long = "baseline",
+        value_name = "FILE",
+        help = "compare the profiled medians with a baseline file"
+    )]
+    baseline: Option<String>,
+    #[arg(
+        long = "save-baseline",
+        value_name = "FILE",
+        help = "write the profiled medians to a baseline file"
+    )]
+    save_baseline: Option<String>,
+    #[arg(
+        long = "tolerance",
+        default_value = "10",
+        help = "slowdown against the baseline in percent that counts as regression"
+    )]
+    tolerance: f64,
+    #[arg(
+        long = "verify",
+        default_value = "false",
+        help = "compare the solutions with the answers stored in <DATA>/<YEAR>/answers.toml"
+    )]
+    verify: bool,
+    #[arg(
+        long = "examples",
+        default_value = "false",
+        conflicts_with_all = ["runtest", "input"],
+        help = "run all examples in <DATA>/<YEAR>/day<DAY>/examples and compare with their expected answers"
     )]
-    numruns: i64,
+    examples: bool,
+    #[arg(
+        long = "record",
+        default_value = "false",
+        help = "write the solutions to <DATA>/<YEAR>/answers.toml"
+    )]
+    record: bool,
+    #[arg(
+        short = 'j',
+        long = "jobs",
+        global = true,
+        default_value = "1",
+        help = "number of days to run in parallel"
+    )]
+    jobs: NonZeroUsize,
+    #[arg(
+        long = "timeout",
+        global = true,
+        value_name = "SECONDS",
+        value_parser = parse_timeout,
+        help = "give up on a run after the given time and report it as TIMEOUT"
+    )]
+    timeout: Option<Duration>,
+    #[arg(
+        short = 'w',
+        long = "watch",
+        default_value = "false",
+        conflicts_with_all = ["input", "examples", "record"],
+        help = "re-run the real input and all examples whenever a file in <DATA>/<YEAR>/day<DAY> changes"
+    )]
+    watch: bool,
+    #[arg(
+        short = 'f',
+        long = "format",
+        value_enum,
+        default_value = "text",
+        help = "output format of the results"
+    )]
+    format: Format,
+    #[arg(
+        short = 'v',
+        long = "verbose",
+        global = true,
+        action = ArgAction::Count,
+        help = "print debug messages of the solvers to stderr, -vv also prints trace messages"
+    )]
+    verbose: u8,
 }
 
-fn main() {
-    let args = Cli::parse();
+#[derive(Subcommand)]
+enum Command {
+    /// Generate the module of a new day, register it and create its data folder
+    New {
+        #[arg(help = "day to generate")]
+        day: i32,
+    },
+    /// Download the input of a day to <DATA>/<YEAR>/day<DAY>/input1.txt unless it is already there
+    ///
+    /// the session token is read from the AOC_SESSION environment variable or `session` in
+    /// <DATA>/config.toml, AOC_BASE_URL or `base_url` point the requests to another server
+    Fetch {
+        #[arg(help = "day to download the input of")]
+        day: i32,
+    },
+    /// Solve a part on the real input and submit the answer
+    ///
+    /// every attempt is kept in <DATA>/<YEAR>/submissions.toml, rejected answers are never submitted
+    /// twice and answers are only submitted `cooldown` seconds from <DATA>/config.toml apart
+    Submit {
+        #[arg(help = "day to submit")]
+        day: i32,
+        #[arg(help = "part to submit")]
+        part: i32,
+    },
+    /// Run every day on the real input and write a report of the answers and durations
+    Report {
+        #[arg(
+            short = 'o',
+            long = "output",
+            value_name = "FILE",
+            help = "write the markdown report to a file instead of stdout"
+        )]
+        output: Option<String>,
+        #[arg(
+            long = "html",
+            value_name = "FILE",
+            help = "also write the report as a standalone html page"
+        )]
+        html: Option<String>,
+        #[arg(
+            long = "readme",
+            value_name = "FILE",
+            help = "replace the day sections below '## Solutions' in a readme"
+        )]
+        readme: Option<String>,
+    },
+}
+
+fn parse_days(arg: &str) -> Result<RangeInclusive<i32>, String> {
+    let parse = |day: &str| {
+        day.trim()
+            .parse::<i32>()
+            .map_err(|_| format!("invalid day '{day}'"))
+    };
+    if arg == "all" {
+        Ok(DAYS)
+    } else if let Some((start, stop)) = arg.split_once("..=") {
+        Ok(parse(start)?..=parse(stop)?)
+    } else if let Some((start, stop)) = arg.split_once("..") {
+        Ok(parse(start)?..=parse(stop)? - 1)
+    } else {
+        parse(arg).map(|day| day..=day)
+    }
+}
+
+fn parse_timeout(arg: &str) -> Result<Duration, String> {
+    arg.parse::<f64>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .filter(|timeout| !timeout.is_zero())
+        .ok_or_else(|| format!("invalid timeout '{arg}', expected a positive number of seconds"))
+}
+
+/// expected answers are kept per year next to the data of the days
+fn answers_path(args: &Cli) -> String {
+    format!("{}/{}/answers.toml", args.data, args.year)
+}
+
+/// settings for the website, kept with the data since it holds the session token
+fn config_path(args: &Cli) -> String {
+    format!("{}/config.toml", args.data)
+}
+
+/// every submitted answer of a season
+fn history_path(args: &Cli) -> String {
+    format!("{}/{}/submissions.toml", args.data, args.year)
+}
+
+/// the tables are written to stdout, failing like `println!` if that is not possible
+const STDOUT: &str = "failed printing to stdout";
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}
+
+/// files the runner reads inputs and expected values from or writes results to
+struct Files {
+    /// path and content of an explicitly given input, read once so stdin can serve both parts
+    input: Option<(String, String)>,
+    answers: Option<AnswerFile>,
+    baseline: Option<Baseline>,
+    new_baseline: Option<Baseline>,
+}
+impl Files {
+    fn open(args: &Cli) -> Result<Self, FileError> {
+        let input = match args.input.as_deref() {
+            Some("-") => Some((
+                "<stdin>".to_owned(),
+                std::io::read_to_string(std::io::stdin()),
+            )),
+            Some(path) => Some((path.to_owned(), std::fs::read_to_string(path))),
+            None => None,
+        };
+        let input = input
+            .map(|(path, input)| match input {
+                Ok(input) => Ok((path, input)),
+                Err(err) => Err(FileError::Io(path.into(), err)),
+            })
+            .transpose()?;
+        let answers = if args.verify || args.record || args.examples || args.watch {
+            Some(AnswerFile::load(answers_path(args))?)
+        } else {
+            None
+        };
+        Ok(Self {
+            input,
+            answers,
+            baseline: args.baseline.as_ref().map(Baseline::load).transpose()?,
+            new_baseline: args
+                .save_baseline
+                .as_ref()
+                .map(Baseline::load)
+                .transpose()?,
+        })
+    }
+    /// path and content of the input for a day, part and dataset
+    fn input(
+        &self,
+        args: &Cli,
+        day: i32,
+        part: i32,
+        dataset: &Dataset,
+    ) -> Result<(String, String), InputError> {
+        match (&self.input, dataset) {
+            (Some(input), _) => Ok(input.clone()),
+            (None, Dataset::Example(name)) => {
+                let path = util::example_path(args.year, day, name, &args.data);
+                match std::fs::read_to_string(&path) {
+                    Ok(input) => Ok((path, input)),
+                    Err(err) => Err(InputError {
+                        day,
+                        part,
+                        tried: vec![(path, err)],
+                    }),
+                }
+            }
+            (None, _) => util::read_input(args.year, day, part, args.runtest, &args.data),
+        }
+    }
+    /// datasets to run a solver on, the examples in examples mode
+    fn datasets(&self, args: &Cli, day: i32, part: i32) -> Vec<Dataset> {
+        if args.examples {
+            self.examples(args, day, part)
+        } else {
+            vec![Dataset::new(args.runtest)]
+        }
+    }
+    /// every example with an expected answer for the part and every example without any expected
+    /// answers yet
+    fn examples(&self, args: &Cli, day: i32, part: i32) -> Vec<Dataset> {
+        let Some(answers) = &self.answers else {
+            return vec![];
+        };
+        util::example_names(args.year, day, &args.data)
+            .into_iter()
+            .map(Dataset::Example)
+            .filter(|dataset| {
+                let Dataset::Example(name) = dataset else {
+                    return false;
+                };
+                answers.get(day, part, dataset).is_some() || !answers.has_example(day, name)
+            })
+            .collect()
+    }
+    /// stores the answer and the profiled median of a run if requested
+    fn update(&mut self, args: &Cli, record: &Record) {
+        if let (Some(answers), Some(answer)) = (self.answers.as_mut(), &record.answer) {
+            if args.record {
+                answers.record(record.day, record.part, &record.dataset, answer);
+            }
+        }
+        if let (Some(baseline), Some(timing)) = (self.new_baseline.as_mut(), record.timing) {
+            baseline.record(record.day, record.part, &timing.stats);
+        }
+    }
+    /// writes the files that were updated during the run, returning their paths
+    fn save(&self, args: &Cli) -> Result<Vec<&Path>, FileError> {
+        let mut saved = vec![];
+        if let Some(answers) = self.answers.as_ref().filter(|_| args.record) {
+            answers.save()?;
+            saved.push(answers.path());
+        }
+        if let Some(baseline) = &self.new_baseline {
+            baseline.save()?;
+            saved.push(baseline.path());
+        }
+        Ok(saved)
+    }
+}
+
+/// puzzle parsed for the previous part, reused by the next part of the same day on the same input
+struct Parsed {
+    day: i32,
+    input: String,
+    puzzle: Puzzle,
+}
+
+/// how a run of a solver ended
+enum Outcome {
+    Solved(Answer),
+    Error(SolveError),
+    Panic(Box<dyn Any + Send>),
+    /// no result within the timeout, `abandoned` if the run was still going and is left running
+    Timeout {
+        after: Duration,
+        abandoned: bool,
+    },
+}
+
+/// describes a timeout, abandoned runs keep using cpu time and memory until the runner exits
+fn timeout_message(after: Duration, abandoned: bool) -> String {
+    if abandoned {
+        format!("no solution after {after:?}, the run could not be stopped and keeps going")
+    } else {
+        format!("no solution after {after:?}")
+    }
+}
+
+/// result of parsing and solving a part once
+struct Run {
+    outcome: Outcome,
+    parse_us: Option<u128>,
+    solve_us: Option<u128>,
+    peak_memory: Option<usize>,
+    /// the parsed puzzle, handed back so the next part can reuse it
+    puzzle: Option<Puzzle>,
+}
+
+/// parses `input` unless the puzzle is given and solves the part, catching panics
+///
+/// with a timeout the run is guarded. a run that finished but took longer than the timeout is
+/// reported as timeout as well, so the outcome does not depend on when the result is picked up
+fn execute(
+    solver: &'static Solver,
+    input: &str,
+    puzzle: Option<Puzzle>,
+    timeout: Option<Duration>,
+) -> Run {
+    let run = move |input: &str, puzzle: Option<Puzzle>| {
+        memory::reset_peak();
+        let (puzzle, parse_us) = match puzzle {
+            Some(puzzle) => (Ok(Ok(puzzle)), None),
+            None => {
+                let t0 = Instant::now();
+                let puzzle = panic::catch_unwind(|| solver.parse(input));
+                (puzzle, Some((Instant::now() - t0).as_micros()))
+            }
+        };
+        let (mut solve_us, mut parsed) = (None, None);
+        let outcome = match puzzle {
+            Ok(Ok(puzzle)) => {
+                let t0 = Instant::now();
+                let result =
+                    panic::catch_unwind(AssertUnwindSafe(|| solver.solve_parsed(puzzle.as_ref())));
+                solve_us = Some((Instant::now() - t0).as_micros());
+                parsed = Some(puzzle);
+                match result {
+                    Ok(Ok(answer)) => Outcome::Solved(answer),
+                    Ok(Err(err)) => Outcome::Error(err),
+                    Err(payload) => Outcome::Panic(payload),
+                }
+            }
+            Ok(Err(err)) => Outcome::Error(err.into()),
+            Err(payload) => Outcome::Panic(payload),
+        };
+        Run {
+            outcome,
+            parse_us,
+            solve_us,
+            peak_memory: Some(memory::peak()),
+            puzzle: parsed,
+        }
+    };
+    let Some(timeout) = timeout else {
+        return run(input, puzzle);
+    };
+    let input = input.to_owned();
+    match guarded(timeout, move || run(&input, puzzle)) {
+        Some(run)
+            if run.parse_us.unwrap_or(0) + run.solve_us.unwrap_or(0) <= timeout.as_micros() =>
+        {
+            run
+        }
+        Some(run) => Run {
+            outcome: Outcome::Timeout {
+                after: timeout,
+                abandoned: false,
+            },
+            ..run
+        },
+        None => Run {
+            outcome: Outcome::Timeout {
+                after: timeout,
+                abandoned: true,
+            },
+            parse_us: None,
+            solve_us: None,
+            peak_memory: None,
+            puzzle: None,
+        },
+    }
+}
+
+/// times repeated runs of parsing and of solving the parsed puzzle and compares the latter against
+/// the baseline
+///
+/// with a timeout the profiling is guarded, every run may take as long as the timeout. returns the
+/// time it was given up after otherwise
+fn profile(
+    solver: &'static Solver,
+    input: &str,
+    args: &Cli,
+    files: &Files,
+) -> Result<Option<Timing>, Duration> {
+    if !args.profile {
+        return Ok(None);
+    }
+    let (warmup, runs, input) = (args.warmup, args.numruns, input.to_owned());
+    let measure = move || {
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            let puzzle = solver.parse(&input).ok()?;
+            let parse = timing::measure(warmup, runs, || solver.parse(&input))?;
+            let solve = timing::measure(warmup, runs, || solver.solve_parsed(puzzle.as_ref()))?;
+            Some((solve, parse))
+        }))
+        .ok()
+        .flatten()
+    };
+    let stats = match args.timeout {
+        Some(timeout) => {
+            let budget = timeout.saturating_mul(u32::try_from(warmup + runs).unwrap_or(u32::MAX));
+            guarded(budget, measure).ok_or(budget)?
+        }
+        None => measure(),
+    };
+    let baseline = files
+        .baseline
+        .as_ref()
+        .and_then(|baseline| baseline.median(solver.day, solver.part));
+    Ok(stats.map(|(solve, parse)| Timing::new(solve, parse, baseline, args.tolerance)))
+}
+
+/// renders an error of a solver, errors in the input together with the offending line
+fn render_error(err: &SolveError, path: &str, input: &str) -> String {
+    let SolveError::Input(err) = err else {
+        return format!("error: {err}");
+    };
+    let mut rendered = format!("error: {}", err.message);
+    if let Some(Location { line, column }) = err.location {
+        let text = input.lines().nth(line - 1).unwrap_or_default();
+        let width = line.to_string().len();
+        rendered += &format!("\n{:width$}--> {path}:{line}:{column}", "");
+        rendered += &format!("\n{:width$} |", "");
+        rendered += &format!("\n{line} | {text}");
+        rendered += &format!("\n{:width$} | {:>column$}", "", "^");
+    }
+    rendered
+}
+
+fn run_single(args: Cli, day: i32, part: i32) -> ExitCode {
+    println!("day: {:?}, part: {:?}", day, part);
+    let mut files = match Files::open(&args) {
+        Ok(files) => files,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let solver = match find_solver(args.year, day, part) {
+        Ok(solver) => solver,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let dataset = Dataset::new(args.runtest);
+    let (path, input) = match files.input(&args, day, part, &dataset) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    // the path goes to stderr with the other diagnostics, stdout only holds the results
+    eprintln!("loading data from '{}'", path);
+
+    let run = execute(solver, &input, None, args.timeout);
+    if let Some(peak_memory) = run.peak_memory {
+        let (parse_us, solve_us) = (run.parse_us.unwrap_or(0), run.solve_us.unwrap_or(0));
+        println!(
+            "Duration: {} us (parse {parse_us} us, solve {solve_us} us), peak memory {}",
+            parse_us + solve_us,
+            format_bytes(peak_memory)
+        );
+    }
+    let solution = match run.outcome {
+        Outcome::Solved(solution) => solution,
+        Outcome::Error(err) => {
+            eprintln!("{}", render_error(&err, &path, &input));
+            return ExitCode::FAILURE;
+        }
+        Outcome::Panic(payload) => panic::resume_unwind(payload),
+        Outcome::Timeout { after, abandoned } => {
+            eprintln!("error: TIMEOUT, {}", timeout_message(after, abandoned));
+            return ExitCode::FAILURE;
+        }
+    };
+    if solution.is_multiline() {
+        println!("Solution:\n{}", solution);
+    } else {
+        println!("Solution: {}", solution);
+    }
+    let mut code = ExitCode::SUCCESS;
+    if let Some(answers) = files.answers.as_mut() {
+        if args.verify {
+            match answers.verify(day, part, &dataset, &solution) {
+                Verdict::Fail(expected) => {
+                    println!("Verify: FAIL, expected {expected}");
+                    code = ExitCode::FAILURE;
+                }
+                verdict => println!("Verify: {verdict}"),
+            }
+        }
+        if args.record {
+            answers.record(day, part, &dataset, &solution);
+        }
+    }
+    let timing = match profile(solver, &input, &args, &files) {
+        Ok(timing) => timing,
+        Err(after) => {
+            eprintln!("error: TIMEOUT, profiling gave up after {after:?}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(timing) = timing {
+        if let Some(baseline) = files.new_baseline.as_mut() {
+            baseline.record(day, part, &timing.stats);
+        }
+        println!("Profile: {} runs", timing.stats.runs);
+        println!("  parse: {}", timing.parse);
+        println!("  solve: {}", timing.stats);
+        if let (Some(baseline), Some(change)) = (timing.baseline, timing.change()) {
+            let flag = if timing.regression {
+                ", REGRESSION"
+            } else {
+                ""
+            };
+            println!("Baseline: median {baseline:.3} us, {change:+.1}%{flag}");
+        }
+        if timing.regression {
+            code = ExitCode::FAILURE;
+        }
+    }
+    if let Err(err) = files.save(&args) {
+        eprintln!("error: {err}");
+        code = ExitCode::FAILURE;
+    }
+    code
+}
+
+/// runs a single solver, catching panics and timeouts so they can be reported like errors
+///
+/// the puzzle is parsed unless `parsed` holds the puzzle of the same day and input, the puzzle is
+/// left in `parsed` for the next part
+fn run_solver(
+    solver: &'static Solver,
+    args: &Cli,
+    dataset: Dataset,
+    files: &Files,
+    parsed: &mut Option<Parsed>,
+) -> Record {
+    let (day, part) = (solver.day, solver.part);
+    if !solver.implemented {
+        let path = util::input_path(args.year, day, part, args.runtest, &args.data);
+        return Record::new(day, part, dataset, path, Status::NotImplemented);
+    }
+    let (path, input) = match files.input(args, day, part, &dataset) {
+        Ok(input) => input,
+        Err(err) => {
+            let path = util::input_path(args.year, day, part, args.runtest, &args.data);
+            let mut record = Record::new(day, part, dataset, path, Status::MissingInput);
+            record.message = Some(err.to_string());
+            return record;
+        }
+    };
+    let mut record = Record::new(day, part, dataset.clone(), path, Status::Solved);
+    let cached = parsed
+        .take()
+        .filter(|parsed| parsed.day == day && parsed.input == input)
+        .map(|parsed| parsed.puzzle);
+    let run = execute(solver, &input, cached, args.timeout);
+    record.parse_us = run.parse_us;
+    record.solve_us = run.solve_us;
+    record.peak_bytes = run.peak_memory;
+    match run.outcome {
+        Outcome::Solved(answer) => {
+            if let Some(answers) = files.answers.as_ref() {
+                if args.verify || args.examples || args.watch {
+                    record.set_verdict(answers.verify(day, part, &dataset, &answer));
+                }
+            }
+            record.answer = Some(answer);
+            match profile(solver, &input, args, files) {
+                Ok(timing) => record.timing = timing,
+                Err(after) => {
+                    record.status = Status::Timeout;
+                    record.message = Some(format!(
+                        "profiling gave up after {after:?}, the runs could not be stopped and keep going"
+                    ));
+                }
+            }
+        }
+        Outcome::Error(err) => {
+            record.status = Status::Error;
+            record.message = Some(err.to_string());
+        }
+        Outcome::Panic(payload) => {
+            record.status = Status::Panic;
+            record.message = Some(panic_message(payload.as_ref()));
+        }
+        Outcome::Timeout { after, abandoned } => {
+            record.status = Status::Timeout;
+            record.message = Some(timeout_message(after, abandoned));
+        }
+    }
+    if let Some(puzzle) = run.puzzle {
+        *parsed = Some(Parsed { day, input, puzzle });
+    }
+    record
+}
+
+/// selected parts of a day together with the datasets to run them on
+struct Task {
+    day: i32,
+    solvers: Vec<(&'static Solver, Vec<Dataset>)>,
+}
+
+/// runs the parts of a day one after another, so they can share the parsed puzzle
+fn run_task(task: &Task, args: &Cli, files: &Files) -> Vec<Record> {
+    let mut parsed = None;
+    let mut records = vec![];
+    for (solver, datasets) in &task.solvers {
+        if datasets.is_empty() {
+            let dir = util::example_dir(args.year, task.day, &args.data);
+            let dataset = Dataset::Example(String::new());
+            let mut record = Record::new(task.day, solver.part, dataset, dir, Status::MissingInput);
+            record.message = Some(format!(
+                "no examples for part {} in '{}'",
+                solver.part, record.input
+            ));
+            records.push(record);
+        }
+        for dataset in datasets {
+            records.push(run_solver(
+                solver,
+                args,
+                dataset.clone(),
+                files,
+                &mut parsed,
+            ));
+        }
+    }
+    records
+}
+
+/// the selected parts of every day in `days` together with the datasets to run them on
+fn tasks(args: &Cli, files: &Files, days: &RangeInclusive<i32>) -> Vec<Task> {
+    days.clone()
+        .map(|day| Task {
+            day,
+            solvers: SOLVERS
+                .iter()
+                .filter(|solver| {
+                    solver.year == args.year
+                        && solver.day == day
+                        && args.part.map_or(true, |part| part == solver.part)
+                })
+                .map(|solver| (solver, files.datasets(args, day, solver.part)))
+                .collect(),
+        })
+        .collect()
+}
+
+/// runs the tasks on `--jobs` threads, handing the records of a day to `done` as soon as every day
+/// before it is done as well
+///
+/// returns the records in day order and the cpu time spent on them if it can be measured
+fn run_pool(
+    tasks: &[Task],
+    args: &Cli,
+    files: &Files,
+    mut done: impl FnMut(i32, &[Record]),
+) -> (Vec<Record>, Option<Duration>) {
+    let (records, cpu) = runner::run_ordered(
+        tasks,
+        args.jobs.get(),
+        |task| run_task(task, args, files),
+        |task, records| done(task.day, records),
+    );
+    (records.concat(), cpu)
+}
+
+/// runs every selected day and part, isolating failures so a single broken solver does not abort the run
+///
+/// the days are distributed over `--jobs` threads, the records are still printed in day order as soon
+/// as all earlier days are done
+fn run_all(args: Cli, days: RangeInclusive<i32>) -> ExitCode {
+    let mut files = match Files::open(&args) {
+        Ok(files) => files,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let printer = Printer {
+        format: args.format,
+        verify: args.verify || args.examples,
+        profile: args.profile,
+        dataset: args.examples,
+    };
+    let tasks = tasks(&args, &files, &days);
+    // panics are reported in the summary table instead of being printed by the default hook
+    panic::set_hook(Box::new(|_| {}));
+
+    let t0 = Instant::now();
+    let mut stdout = std::io::stdout();
+    printer.header(&mut stdout).expect(STDOUT);
+    let (records, cpu) = run_pool(&tasks, &args, &files, |_, records| {
+        for record in records {
+            printer.record(&mut stdout, record).expect(STDOUT);
+        }
+    });
+    let _ = panic::take_hook();
+    // the summary would break machine-readable output, so it goes to stderr there
+    let summary = |line: String| {
+        if args.format == Format::Text {
+            println!("{line}")
+        } else {
+            eprintln!("{line}")
+        }
+    };
+    summary(format!(
+        "Total duration: {} us",
+        (Instant::now() - t0).as_micros()
+    ));
+    let abandoned = runner::abandoned();
+    if let Some(cpu) = cpu.filter(|_| args.jobs.get() > 1) {
+        let without = if abandoned > 0 {
+            format!(", without {abandoned} abandoned run(s)")
+        } else {
+            String::new()
+        };
+        summary(format!(
+            "CPU time: {} us on {} jobs{without}",
+            cpu.as_micros(),
+            args.jobs
+        ));
+    }
+    if abandoned > 0 {
+        summary(format!(
+            "{abandoned} timed out run(s) could not be stopped and keep going until the runner exits"
+        ));
+    }
+
+    for record in &records {
+        files.update(&args, record);
+    }
+    match files.save(&args) {
+        Ok(saved) => {
+            for path in saved {
+                summary(format!("updated '{}'", path.display()));
+            }
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+    let failed = records.iter().filter(|record| record.failed()).count();
+    if failed > 0 {
+        summary(format!("{failed} solver(s) failed"));
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// how often the watched directory is checked for changes
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// runs the real input and all examples of a day, reporting every answer that changed since the
+/// previous run
+fn run_watched(
+    args: &Cli,
+    day: i32,
+    printer: &Printer,
+    previous: &mut HashMap<(i32, Dataset), Option<Answer>>,
+) {
+    let files = match Files::open(args) {
+        Ok(files) => files,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return;
+        }
+    };
+    let solvers = SOLVERS.iter().filter(|solver| {
+        solver.year == args.year
+            && solver.day == day
+            && args.part.map_or(true, |part| part == solver.part)
+    });
+    let mut parsed = None;
+    let mut changes = vec![];
+    let mut stdout = std::io::stdout();
+    printer.header(&mut stdout).expect(STDOUT);
+    for solver in solvers {
+        let mut datasets = vec![Dataset::new(args.runtest)];
+        datasets.extend(files.examples(args, day, solver.part));
+        for dataset in datasets {
+            let record = run_solver(solver, args, dataset, &files, &mut parsed);
+            printer.record(&mut stdout, &record).expect(STDOUT);
+            let key = (solver.part, record.dataset.clone());
+            match previous.insert(key, record.answer.clone()) {
+                Some(answer) if answer != record.answer => changes.push(format!(
+                    "part {} {}: {} -> {}",
+                    record.part,
+                    record.dataset,
+                    display_answer(answer.as_ref()),
+                    display_answer(record.answer.as_ref()),
+                )),
+                _ => {}
+            }
+        }
+    }
+    for change in changes {
+        println!("changed {change}");
+    }
+}
+
+fn display_answer(answer: Option<&Answer>) -> String {
+    answer.map_or("-".to_owned(), |answer| answer.to_string())
+}
+
+/// polls the data directory of a day and the answers file and re-runs the day on every change until
+/// interrupted
+fn run_watch(args: Cli, day: i32) -> ExitCode {
+    let dir = PathBuf::from(util::day_dir(args.year, day, &args.data));
+    let answers = PathBuf::from(answers_path(&args));
+    let printer = Printer {
+        format: Format::Text,
+        verify: true,
+        profile: args.profile,
+        dataset: true,
+    };
+    // panics are reported in the table, the watch goes on
+    panic::set_hook(Box::new(|_| {}));
+    println!("watching '{}', press Ctrl-C to stop", dir.display());
+    let mut previous = HashMap::new();
+    let mut state = None;
+    loop {
+        let current = Snapshot::take(&[&dir, &answers]);
+        if current.changed(state.as_ref()) {
+            if state.is_some() {
+                println!("\nchange detected, running day {day} again");
+            }
+            state = Some(current);
+            run_watched(&args, day, &printer, &mut previous);
+        }
+        thread::sleep(WATCH_INTERVAL);
+    }
+}
+
+/// generates a new day in the crate the runner is started from
+fn run_new(args: &Cli, day: i32) -> ExitCode {
+    match scaffold::scaffold(args.year, day, Path::new(""), &args.data) {
+        Ok(written) => {
+            for path in written {
+                println!("wrote '{}'", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
 
-    println!("day: {:?}, part: {:?}", args.day, args.part);
-    let Cli {
+/// downloads the input of a day, an input that was downloaded before is never requested again
+fn run_fetch(args: &Cli, day: i32) -> ExitCode {
+    if !DAYS.contains(&day) {
+        eprintln!("error: {}", SolverError::UnknownDay(day));
+        return ExitCode::FAILURE;
+    }
+    let path = PathBuf::from(util::input_path(args.year, day, 1, false, &args.data));
+    if path.exists() {
+        println!("'{}' already exists, not downloading", path.display());
+        return ExitCode::SUCCESS;
+    }
+    let client = match Config::load(config_path(args)) {
+        Ok(config) => Client::new(&config),
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = match client.and_then(|client| client.input(args.year, day)) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    // the input is moved into place once complete, so an interrupted download is not mistaken for
+    // a cached input
+    let partial = path.with_extension("txt.part");
+    let written = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| std::fs::write(&partial, input))
+        .and_then(|_| std::fs::rename(&partial, &path));
+    match written {
+        Ok(()) => {
+            println!("wrote '{}'", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", FileError::Io(path, err));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// runs every day of the season one after the other and writes the report
+fn run_report(
+    args: &Cli,
+    output: Option<&str>,
+    html: Option<&str>,
+    readme: Option<&str>,
+) -> ExitCode {
+    let files = match Files::open(args) {
+        Ok(files) => files,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    panic::set_hook(Box::new(|_| {}));
+    let tasks = tasks(args, &files, &DAYS);
+    let (records, _) = run_pool(&tasks, args, &files, |day, _| {
+        eprintln!("finished day {day}")
+    });
+    let _ = panic::take_hook();
+    let report = Report {
+        year: args.year,
+        records: &records,
+    };
+    let write = |path: &str, content: &str| {
+        std::fs::write(path, content).map_err(|err| FileError::Io(path.into(), err))
+    };
+    let mut written = vec![];
+    let result = (|| {
+        match output {
+            Some(path) => {
+                write(path, &report.markdown())?;
+                written.push(path.to_owned());
+            }
+            None => print!("{}", report.markdown()),
+        }
+        if let Some(path) = html {
+            write(path, &report.html())?;
+            written.push(path.to_owned());
+        }
+        if let Some(path) = readme {
+            let text =
+                std::fs::read_to_string(path).map_err(|err| FileError::Io(path.into(), err))?;
+            let Some(text) = report::replace_solutions(&text, &report.solutions()) else {
+                return Err(FileError::Io(
+                    path.into(),
+                    std::io::Error::other(format!("no '{}' section", report::SOLUTIONS_HEADING)),
+                ));
+            };
+            write(path, &text)?;
+            written.push(path.to_owned());
+        }
+        Ok(())
+    })();
+    for path in written {
+        eprintln!("wrote '{path}'");
+    }
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// solves a part on the real input and submits the answer unless the history refuses it
+fn run_submit(args: &Cli, day: i32, part: i32) -> ExitCode {
+    let solver = match find_solver(args.year, day, part) {
+        Ok(solver) => solver,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let (path, input) = match util::read_input(args.year, day, part, false, &args.data) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let answer = match solver.solve(&input) {
+        Ok(answer) => answer,
+        Err(err) => {
+            eprintln!("{}", render_error(&err, &path, &input));
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("Solution: {answer}");
+    let (config, mut history) = match Config::load(config_path(args))
+        .and_then(|config| Ok((config, History::load(history_path(args))?)))
+    {
+        Ok(files) => files,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    if let Err(refusal) = history.check(day, part, &answer, now, config.cooldown) {
+        eprintln!("error: not submitting, {refusal}");
+        return ExitCode::FAILURE;
+    }
+    let submitted =
+        Client::new(&config).and_then(|client| client.submit(args.year, day, part, &answer));
+    let (reply, message) = match submitted {
+        Ok(reply) => reply,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("{message}");
+    let correct = reply == Reply::Correct;
+    history.record(
         day,
         part,
-        runtest,
-        data,
-        profile,
-        numruns,
-    } = args;
-    let input = util::load_file(day, part, runtest, &data);
+        &Attempt {
+            answer,
+            reply,
+            timestamp: now,
+        },
+    );
+    if let Err(err) = history.save() {
+        eprintln!("error: {err}");
+        return ExitCode::FAILURE;
+    }
+    if correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
 
-    let function = dispatch_function(day, part);
+fn main() -> ExitCode {
+    let args = Cli::parse();
+    let level = match args.verbose {
+        0 => log::LevelFilter::Warn,
+        1 => log::LevelFilter::Debug,
+        _ => log::LevelFilter::Trace,
+    };
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(level);
+    }
+    match args.command {
+        Some(Command::New { day }) => return run_new(&args, day),
+        Some(Command::Fetch { day }) => return run_fetch(&args, day),
+        Some(Command::Submit { day, part }) => return run_submit(&args, day, part),
+        Some(Command::Report {
+            ref output,
+            ref html,
+            ref readme,
+        }) => return run_report(&args, output.as_deref(), html.as_deref(), readme.as_deref()),
+        None => {}
+    }
+    let days = args
+        .day
+        .clone()
+        .expect("the day is required without a subcommand");
+    if let Err(err) = check_selection(args.year, &days, args.part) {
+        eprintln!("error: {err}");
+        return ExitCode::FAILURE;
+    }
+    if args.input.is_some() && days.start() != days.end() {
+        eprintln!("error: --input can only be used with a single day");
+        return ExitCode::FAILURE;
+    }
+    if args.watch {
+        if days.start() != days.end() {
+            eprintln!("error: --watch can only be used with a single day");
+            return ExitCode::FAILURE;
+        }
+        return run_watch(args, *days.start());
+    }
 
-    if profile {
-        for _ in 0..numruns {
-            let sol = function(&input);
-            dbg!(sol);
+    match (days.start(), days.end(), args.part) {
+        (&start, &stop, Some(part))
+            if start == stop && args.format == Format::Text && !args.examples =>
+        {
+            run_single(args, start, part)
         }
+        _ => run_all(args, days),
     }
-    let t0 = Instant::now();
-    let solution = function(&input);
-    println!("Duration: {} us", (Instant::now() - t0).as_micros());
-    println!("Solution: {}", solution);
 }
@@ -0,0 +1,81 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    // bytes allocated by the thread, memory freed by another thread than it was allocated on can make
+    // it negative
+    static CURRENT: Cell<isize> = const { Cell::new(0) };
+    static START: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+}
+
+/// system allocator keeping track of the memory allocated by each thread, installed with
+/// `#[global_allocator]` by the runner
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            count(layout.size() as isize);
+        }
+        ptr
+    }
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            count(layout.size() as isize);
+        }
+        ptr
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        count(-(layout.size() as isize));
+    }
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            count(new_size as isize - layout.size() as isize);
+        }
+        new_ptr
+    }
+}
+
+fn count(bytes: isize) {
+    // the thread locals are already gone while a thread shuts down
+    let _ = CURRENT.try_with(|current| {
+        let current = current.get() + bytes;
+        CURRENT.set(current);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(current)));
+    });
+}
+
+/// starts measuring the peak memory of the calling thread
+pub fn reset_peak() {
+    let current = CURRENT.get();
+    START.set(current);
+    PEAK.set(current);
+}
+
+/// highest amount of memory in bytes the calling thread held since `reset_peak` on top of what it
+/// held before, always 0 if the `CountingAllocator` is not installed
+pub fn peak() -> usize {
+    (PEAK.get() - START.get()).max(0) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_peak() {
+        // the test binary uses the system allocator, so the counting is driven by hand
+        reset_peak();
+        count(1000);
+        count(-600);
+        count(200);
+        assert_eq!(peak(), 1000);
+        reset_peak();
+        count(-600);
+        assert_eq!(peak(), 0);
+    }
+}
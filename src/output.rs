@@ -0,0 +1,369 @@
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+use crate::answers::{Dataset, Verdict};
+use crate::timing::Timing;
+use crate::util::Answer;
+
+/// how the runner prints its results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    Text,
+    // one json object per line
+    Json,
+    Csv,
+    Markdown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    NotImplemented,
+    MissingInput,
+    Error,
+    Panic,
+    Timeout,
+}
+
+/// outcome of running a single day and part
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub day: i32,
+    pub part: i32,
+    pub dataset: Dataset,
+    pub input: String,
+    pub status: Status,
+    pub answer: Option<Answer>,
+    pub parse_us: Option<u128>,
+    pub solve_us: Option<u128>,
+    /// highest amount of memory allocated while parsing and solving, in bytes
+    pub peak_bytes: Option<usize>,
+    pub verdict: Option<Verdict>,
+    pub expected: Option<Answer>,
+    /// statistics of repeated runs when profiling
+    pub timing: Option<Timing>,
+    /// error, panic or timeout message
+    pub message: Option<String>,
+}
+impl Record {
+    pub fn new(day: i32, part: i32, dataset: Dataset, input: String, status: Status) -> Self {
+        Self {
+            day,
+            part,
+            dataset,
+            input,
+            status,
+            answer: None,
+            parse_us: None,
+            solve_us: None,
+            peak_bytes: None,
+            verdict: None,
+            expected: None,
+            timing: None,
+            message: None,
+        }
+    }
+    pub fn set_verdict(&mut self, verdict: Verdict) {
+        if let Verdict::Fail(expected) = &verdict {
+            self.expected = Some(expected.clone());
+        }
+        self.verdict = Some(verdict);
+    }
+    /// errors, panics, timeouts, wrong answers and timing regressions fail the run
+    pub fn failed(&self) -> bool {
+        matches!(self.status, Status::Error | Status::Panic | Status::Timeout)
+            || matches!(self.verdict, Some(Verdict::Fail(_)))
+            || self.timing.is_some_and(|timing| timing.regression)
+    }
+    /// total time spent in the solver, parsing included
+    pub fn duration_us(&self) -> Option<u128> {
+        match (self.parse_us, self.solve_us) {
+            (None, None) => None,
+            (parse, solve) => Some(parse.unwrap_or(0) + solve.unwrap_or(0)),
+        }
+    }
+    /// short description used in the human readable formats
+    pub(crate) fn summary(&self) -> String {
+        let message = self.message.as_deref().unwrap_or_default();
+        match self.status {
+            Status::Solved => {
+                let mut summary = display_or(self.answer.as_ref(), "");
+                if let Some(expected) = &self.expected {
+                    summary += &format!(" (expected {expected})");
+                }
+                if let Some(change) = self.timing.and_then(|timing| timing.change()) {
+                    let flag = if self.timing.is_some_and(|timing| timing.regression) {
+                        ", REGRESSION"
+                    } else {
+                        ""
+                    };
+                    summary += &format!(" ({change:+.1}% against baseline{flag})");
+                }
+                summary
+            }
+            Status::NotImplemented => "not implemented".to_owned(),
+            Status::MissingInput => message.to_owned(),
+            Status::Error => format!("ERROR: {message}"),
+            Status::Panic => format!("PANIC: {message}"),
+            Status::Timeout => format!("TIMEOUT: {message}"),
+        }
+    }
+}
+
+const CSV_HEADER: [&str; 20] = [
+    "day",
+    "part",
+    "dataset",
+    "input",
+    "status",
+    "answer",
+    "parse_us",
+    "solve_us",
+    "peak_bytes",
+    "verdict",
+    "expected",
+    "runs",
+    "min_us",
+    "median_us",
+    "mean_us",
+    "p95_us",
+    "stddev_us",
+    "parse_median_us",
+    "baseline_us",
+    "message",
+];
+
+/// writes records one by one so long runs show progress
+pub struct Printer {
+    pub format: Format,
+    /// adds the status column to the text table
+    pub verify: bool,
+    /// adds the timing statistics to the tables
+    pub profile: bool,
+    /// adds the dataset column to the text table
+    pub dataset: bool,
+}
+impl Printer {
+    pub fn header(&self, out: &mut impl Write) -> io::Result<()> {
+        match self.format {
+            Format::Text => {
+                let duration = if self.profile {
+                    format!(
+                        "{:>10} {:>10} {:>10} {:>10} {:>10} {:>10}",
+                        "parse [us]", "min [us]", "median", "mean", "p95", "stddev"
+                    )
+                } else {
+                    format!("{:>10} {:>10} {:>10}", "parse [us]", "solve [us]", "memory")
+                };
+                let row = self.text_row("day", "part", &duration, "status", "example", "solution");
+                writeln!(out, "{row}")
+            }
+            Format::Json => Ok(()),
+            Format::Csv => writeln!(out, "{}", CSV_HEADER.join(",")),
+            Format::Markdown => {
+                let mut header = "| day | part | dataset | status | answer | parse [us] | solve [us] | memory | verdict |".to_owned();
+                let mut separator = "|----:|-----:|---------|--------|--------|-----------:|-----------:|-------:|---------|".to_owned();
+                if self.profile {
+                    header += " parse median [us] | median [us] | p95 [us] | stddev [us] |";
+                    separator += "------------------:|------------:|---------:|------------:|";
+                }
+                writeln!(out, "{header}\n{separator}")
+            }
+        }
+    }
+    pub fn record(&self, out: &mut impl Write, record: &Record) -> io::Result<()> {
+        match self.format {
+            Format::Text => {
+                let duration = match record.timing {
+                    Some(timing) => format!(
+                        "{:>10.1} {:>10.1} {:>10.1} {:>10.1} {:>10.1} {:>10.1}",
+                        timing.parse.median,
+                        timing.stats.min,
+                        timing.stats.median,
+                        timing.stats.mean,
+                        timing.stats.p95,
+                        timing.stats.stddev
+                    ),
+                    None if self.profile => format!("{:>65}", "-"),
+                    None => format!(
+                        "{:>10} {:>10} {:>10}",
+                        display_or(record.parse_us, "-"),
+                        display_or(record.solve_us, "-"),
+                        display_or(record.peak_bytes.map(format_bytes), "-")
+                    ),
+                };
+                let verdict = display_or(record.verdict.as_ref(), "");
+                let dataset = match &record.dataset {
+                    Dataset::Example(name) => name.clone(),
+                    dataset => dataset.to_string(),
+                };
+                let row = self.text_row(
+                    &record.day.to_string(),
+                    &record.part.to_string(),
+                    &duration,
+                    &verdict,
+                    &dataset,
+                    &record.summary(),
+                );
+                writeln!(out, "{row}")
+            }
+            Format::Json => writeln!(
+                out,
+                "{}",
+                serde_json::to_string(record).expect("records are always serializable")
+            ),
+            Format::Csv => {
+                let fields = [
+                    record.day.to_string(),
+                    record.part.to_string(),
+                    record.dataset.to_string(),
+                    record.input.clone(),
+                    json_str(&record.status),
+                    display_or(record.answer.as_ref(), ""),
+                    display_or(record.parse_us, ""),
+                    display_or(record.solve_us, ""),
+                    display_or(record.peak_bytes, ""),
+                    display_or(record.verdict.as_ref(), ""),
+                    display_or(record.expected.as_ref(), ""),
+                    display_or(record.timing.map(|timing| timing.stats.runs), ""),
+                    display_or(record.timing.map(|timing| timing.stats.min), ""),
+                    display_or(record.timing.map(|timing| timing.stats.median), ""),
+                    display_or(record.timing.map(|timing| timing.stats.mean), ""),
+                    display_or(record.timing.map(|timing| timing.stats.p95), ""),
+                    display_or(record.timing.map(|timing| timing.stats.stddev), ""),
+                    display_or(record.timing.map(|timing| timing.parse.median), ""),
+                    display_or(record.timing.and_then(|timing| timing.baseline), ""),
+                    record.message.clone().unwrap_or_default(),
+                ];
+                let fields: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+                writeln!(out, "{}", fields.join(","))
+            }
+            Format::Markdown => {
+                let answer = match record.status {
+                    Status::Solved => display_or(record.answer.as_ref(), ""),
+                    _ => record.summary(),
+                };
+                let mut row = format!(
+                    "| {} | {} | {} | {} | {} | {} | {} | {} | {} |",
+                    record.day,
+                    record.part,
+                    record.dataset,
+                    json_str(&record.status),
+                    markdown_cell(&answer),
+                    display_or(record.parse_us, ""),
+                    display_or(record.solve_us, ""),
+                    display_or(record.peak_bytes.map(format_bytes), ""),
+                    display_or(record.verdict.as_ref(), ""),
+                );
+                if self.profile {
+                    let stat =
+                        |value: Option<f64>| display_or(value.map(|v| format!("{v:.1}")), "");
+                    row += &format!(
+                        " {} | {} | {} | {} |",
+                        stat(record.timing.map(|timing| timing.parse.median)),
+                        stat(record.timing.map(|timing| timing.stats.median)),
+                        stat(record.timing.map(|timing| timing.stats.p95)),
+                        stat(record.timing.map(|timing| timing.stats.stddev)),
+                    );
+                }
+                writeln!(out, "{row}")
+            }
+        }
+    }
+    /// the status and dataset columns are only shown if enabled, multi-line answers are indented to
+    /// the solution column
+    fn text_row(
+        &self,
+        day: &str,
+        part: &str,
+        duration: &str,
+        status: &str,
+        dataset: &str,
+        solution: &str,
+    ) -> String {
+        let status = if self.verify {
+            format!("{status:8}")
+        } else {
+            String::new()
+        };
+        let dataset = if self.dataset {
+            format!("{dataset:20} ")
+        } else {
+            String::new()
+        };
+        let column = 12 + duration.len().max(14) + status.len() + dataset.len();
+        let solution = solution.replace('\n', &format!("\n{:column$}", ""));
+        format!("{day:>4} {part:>5} {duration:>14}  {status}{dataset}{solution}")
+    }
+}
+
+/// human readable amount of memory with binary prefixes
+pub fn format_bytes(bytes: usize) -> String {
+    let mut value = bytes as f64;
+    for unit in ["B", "KiB", "MiB"] {
+        if value < 1024. {
+            return match unit {
+                "B" => format!("{bytes} B"),
+                unit => format!("{value:.1} {unit}"),
+            };
+        }
+        value /= 1024.;
+    }
+    format!("{value:.1} GiB")
+}
+
+fn display_or<T: std::fmt::Display>(value: Option<T>, default: &str) -> String {
+    value.map_or(default.to_owned(), |value| value.to_string())
+}
+fn json_str(value: &impl Serialize) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_owned))
+        .unwrap_or_default()
+}
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+pub(crate) fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_record_json() {
+        let mut record = Record::new(
+            1,
+            2,
+            Dataset::Test,
+            "data/day1/test_input2.txt".to_owned(),
+            Status::Solved,
+        );
+        record.answer = Some(Answer::from(281));
+        record.solve_us = Some(12);
+        record.set_verdict(Verdict::Fail(Answer::from(280)));
+        assert!(record.failed());
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"day":1,"part":2,"dataset":"test","input":"data/day1/test_input2.txt","status":"solved","answer":281,"parse_us":null,"solve_us":12,"peak_bytes":null,"verdict":"FAIL","expected":280,"timing":null,"message":null}"#
+        );
+    }
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+    }
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("done!"), "done!");
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+}
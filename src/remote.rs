@@ -0,0 +1,244 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::toml_file::{FileError, TomlFile};
+use crate::util::Answer;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// environment variable holding the session token, takes precedence over the config file
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// environment variable holding the base url, takes precedence over the config file
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// seconds to wait between two submissions, the website does not accept answers more often
+pub const DEFAULT_COOLDOWN: u64 = 60;
+
+/// settings for talking to the advent of code website, stored as toml next to the data
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// cooldown = 60
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// value of the `session` cookie of a logged in browser
+    pub session: Option<String>,
+    pub base_url: String,
+    /// minimum number of seconds between two submissions
+    pub cooldown: u64,
+}
+impl Config {
+    /// reads the config file, a missing file is treated as empty, the environment variables
+    /// override the values of the file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, FileError> {
+        let file = TomlFile::load(path)?;
+        let document = file.document();
+        let string = |key: &str| document.get(key).and_then(|item| item.as_str());
+        let mut config = Self {
+            session: string("session").map(str::to_owned),
+            base_url: string("base_url").unwrap_or(DEFAULT_BASE_URL).to_owned(),
+            cooldown: document
+                .get("cooldown")
+                .and_then(|item| item.as_integer())
+                .and_then(|cooldown| u64::try_from(cooldown).ok())
+                .unwrap_or(DEFAULT_COOLDOWN),
+        };
+        if let Ok(session) = std::env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var(BASE_URL_VAR) {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+}
+
+/// how the website judged a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reply {
+    Correct,
+    /// rejected answer with the hint whether it is too high or too low if given
+    Wrong(Option<String>),
+    /// the previous answer was submitted too recently, with the seconds left to wait if given
+    TooRecent(Option<u64>),
+    /// the part is already solved or not unlocked yet
+    WrongLevel,
+    Unknown,
+}
+impl Reply {
+    /// judges the page returned for a submission, returns the reply and the text of the message
+    pub fn parse(html: &str) -> (Self, String) {
+        let article = html
+            .split_once("<article>")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(html, |(article, _)| article);
+        let message = strip_tags(article);
+        let reply = if message.contains("That's the right answer") {
+            Reply::Correct
+        } else if message.contains("That's not the right answer") {
+            let hint = ["too high", "too low"]
+                .into_iter()
+                .find(|hint| message.contains(&format!("your answer is {hint}")));
+            Reply::Wrong(hint.map(str::to_owned))
+        } else if message.contains("You gave an answer too recently") {
+            Reply::TooRecent(wait_time(&message))
+        } else if message.contains("You don't seem to be solving the right level") {
+            Reply::WrongLevel
+        } else {
+            Reply::Unknown
+        };
+        (reply, message)
+    }
+}
+impl Display for Reply {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reply::Correct => f.write_str("correct"),
+            Reply::Wrong(_) => f.write_str("wrong"),
+            Reply::TooRecent(_) => f.write_str("too_recent"),
+            Reply::WrongLevel => f.write_str("wrong_level"),
+            Reply::Unknown => f.write_str("unknown"),
+        }
+    }
+}
+
+/// text of an html fragment with the tags removed and the whitespace collapsed
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for char in html.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            char if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// seconds of a wait time like "You have 4m 32s left to wait"
+fn wait_time(message: &str) -> Option<u64> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (time, _) = rest.split_once(" left to wait")?;
+    time.split_whitespace()
+        .map(|part| {
+            let (value, factor) = match part.strip_suffix('m') {
+                Some(minutes) => (minutes, 60),
+                None => (part.strip_suffix('s')?, 1),
+            };
+            Some(value.parse::<u64>().ok()? * factor)
+        })
+        .sum()
+}
+
+/// error talking to the advent of code website
+#[derive(Debug)]
+pub enum RemoteError {
+    NoSession,
+    /// the server answered with an error status, e.g. 404 for a day that is not unlocked yet
+    Status(u16, String),
+    Transport(String),
+}
+impl Display for RemoteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RemoteError::NoSession => write!(
+                f,
+                "no session token, set {SESSION_VAR} or `session` in the config file"
+            ),
+            RemoteError::Status(status, message) => {
+                write!(f, "server responded with status {status}: {message}")
+            }
+            RemoteError::Transport(message) => write!(f, "request failed: {message}"),
+        }
+    }
+}
+impl std::error::Error for RemoteError {}
+impl From<ureq::Error> for RemoteError {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(status, response) => {
+                let message = response.into_string().unwrap_or_default();
+                RemoteError::Status(status, message.trim().to_owned())
+            }
+            ureq::Error::Transport(transport) => RemoteError::Transport(transport.to_string()),
+        }
+    }
+}
+
+/// client authenticated with the session token of the config
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, RemoteError> {
+        let session = config.session.clone().ok_or(RemoteError::NoSession)?;
+        Ok(Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!(
+                    env!("CARGO_PKG_NAME"),
+                    "/",
+                    env!("CARGO_PKG_VERSION")
+                ))
+                .build(),
+            base_url: config.base_url.trim_end_matches('/').to_owned(),
+            session,
+        })
+    }
+    /// downloads the puzzle input of a day
+    pub fn input(&self, year: i32, day: i32) -> Result<String, RemoteError> {
+        let response = self
+            .agent
+            .get(&format!("{}/{year}/day/{day}/input", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+        response
+            .into_string()
+            .map_err(|err| RemoteError::Transport(err.to_string()))
+    }
+    /// posts the answer of a part, returns the judgement and the message of the website
+    pub fn submit(
+        &self,
+        year: i32,
+        day: i32,
+        part: i32,
+        answer: &Answer,
+    ) -> Result<(Reply, String), RemoteError> {
+        let response = self
+            .agent
+            .post(&format!("{}/{year}/day/{day}/answer", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[
+                ("level", &part.to_string()),
+                ("answer", &answer.to_string()),
+            ])?;
+        let html = response
+            .into_string()
+            .map_err(|err| RemoteError::Transport(err.to_string()))?;
+        Ok(Reply::parse(&html))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_parse_reply() {
+        let page = "<main>\n<article><p>That's not the right answer; your answer is too high.  If you're stuck, \
+            make sure you're using the full input data. <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>\n</main>";
+        let (reply, message) = Reply::parse(page);
+        assert_eq!(reply, Reply::Wrong(Some("too high".to_owned())));
+        assert!(message.starts_with("That's not the right answer; your answer is too high. If"));
+        assert!(message.ends_with("[Return to Day 1]"));
+        let page = "<article><p>You gave an answer too recently; you have to wait after submitting an answer \
+            before trying again. You have 4m 32s left to wait.</p></article>";
+        assert_eq!(Reply::parse(page).0, Reply::TooRecent(Some(272)));
+        let page = "<article><p>That's the right answer! You are <span>one gold star</span> closer.</p></article>";
+        assert_eq!(Reply::parse(page).0, Reply::Correct);
+        assert_eq!(Reply::parse("<html></html>").0, Reply::Unknown);
+    }
+}
@@ -0,0 +1,314 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::output::{format_bytes, markdown_cell, Record, Status};
+
+/// heading of the readme section holding the answers of every day
+pub const SOLUTIONS_HEADING: &str = "## Solutions";
+/// width of the bars in the text chart in characters
+const CHART_WIDTH: usize = 40;
+/// width of the bars in the svg chart in pixels
+const SVG_WIDTH: usize = 400;
+const SVG_ROW: usize = 18;
+
+/// path of the module solving a day relative to the crate root
+pub fn source_path(year: i32, day: i32) -> String {
+    format!("src/days/year{year}/day{day}.rs")
+}
+
+/// results of running every day of a season, rendered as markdown or html
+pub struct Report<'a> {
+    pub year: i32,
+    pub records: &'a [Record],
+}
+impl Report<'_> {
+    /// table of the answers and durations, a text bar chart of the durations and the solutions section
+    pub fn markdown(&self) -> String {
+        let mut text = format!("# Advent of Code {}\n\n", self.year);
+        text += "| day | part | answer | parse [us] | solve [us] | memory |\n";
+        text += "|----:|-----:|--------|-----------:|-----------:|-------:|\n";
+        for record in self.records {
+            let _ = writeln!(
+                text,
+                "| [{}]({}) | {} | {} | {} | {} | {} |",
+                record.day,
+                source_path(self.year, record.day),
+                record.part,
+                markdown_cell(&answer_or_summary(record)),
+                display_or(record.parse_us),
+                display_or(record.solve_us),
+                display_or(record.peak_bytes.map(format_bytes)),
+            );
+        }
+        text += "\n## Durations\n\n```text\n";
+        text += &self.text_chart();
+        text += "```\n\n";
+        text += SOLUTIONS_HEADING;
+        text += "\n\n";
+        text += &self.solutions();
+        text
+    }
+    /// standalone page with the table, an svg bar chart of the durations and links to the sources
+    pub fn html(&self) -> String {
+        let title = format!("Advent of Code {}", self.year);
+        let mut rows = String::new();
+        for record in self.records {
+            let _ = writeln!(
+                rows,
+                "<tr><td><a href=\"{}\">{}</a></td><td>{}</td><td><pre>{}</pre></td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                source_path(self.year, record.day),
+                record.day,
+                record.part,
+                escape(&answer_or_summary(record)),
+                display_or(record.parse_us),
+                display_or(record.solve_us),
+                display_or(record.peak_bytes.map(format_bytes)),
+            );
+        }
+        format!(
+            r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body {{ font-family: sans-serif; }}
+table {{ border-collapse: collapse; }}
+td, th {{ padding: 2px 8px; text-align: right; border-bottom: 1px solid #ddd; }}
+pre {{ margin: 0; text-align: left; }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+<table>
+<tr><th>day</th><th>part</th><th>answer</th><th>parse [us]</th><th>solve [us]</th><th>memory</th></tr>
+{rows}</table>
+<h2>Durations</h2>
+{chart}</body>
+</html>
+"#,
+            chart = self.svg_chart()
+        )
+    }
+    /// the readme section of every day, in the layout the trycmd test of the readme checks
+    pub fn solutions(&self) -> String {
+        let mut days: BTreeMap<i32, Vec<&Record>> = BTreeMap::new();
+        for record in self.records {
+            days.entry(record.day).or_default().push(record);
+        }
+        let sections: Vec<String> = days
+            .into_iter()
+            .map(|(day, records)| day_section(self.year, day, &records))
+            .collect();
+        sections.join("\n")
+    }
+    /// total duration of every solved part, the bars are scaled to the slowest one
+    fn durations(&self) -> Vec<(&Record, u128)> {
+        self.records
+            .iter()
+            .filter(|record| record.status == Status::Solved)
+            .filter_map(|record| Some((record, record.duration_us()?)))
+            .collect()
+    }
+    fn text_chart(&self) -> String {
+        let durations = self.durations();
+        let max = durations.iter().map(|(_, us)| *us).max().unwrap_or(0);
+        let mut chart = String::new();
+        for (record, us) in durations {
+            let _ = writeln!(
+                chart,
+                "day {:>2} part {} |{:<CHART_WIDTH$}| {us:>10} us",
+                record.day,
+                record.part,
+                bar(us, max, CHART_WIDTH),
+            );
+        }
+        chart
+    }
+    fn svg_chart(&self) -> String {
+        let durations = self.durations();
+        let max = durations.iter().map(|(_, us)| *us).max().unwrap_or(0);
+        let label = 110;
+        let height = durations.len() * SVG_ROW;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{height}\" font-size=\"12\">\n",
+            label + SVG_WIDTH + 100
+        );
+        for (row, (record, us)) in durations.iter().enumerate() {
+            let y = row * SVG_ROW;
+            let width = (*us * SVG_WIDTH as u128).checked_div(max).unwrap_or(0) as usize;
+            let _ = writeln!(
+                svg,
+                "<text x=\"0\" y=\"{}\">day {} part {}</text><rect x=\"{label}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#4a7\"/><text x=\"{}\" y=\"{}\">{us} us</text>",
+                y + 13,
+                record.day,
+                record.part,
+                y + 2,
+                width.max(1),
+                SVG_ROW - 4,
+                label + width + 6,
+                y + 13,
+            );
+        }
+        svg += "</svg>\n";
+        svg
+    }
+}
+
+/// readme section of a day with the output of the runner for every solved part, other parts are
+/// listed as not solved yet
+pub fn day_section(year: i32, day: i32, records: &[&Record]) -> String {
+    let mut section = format!("### [Day {day}]({})\n", source_path(year, day));
+    for part in 1..=2 {
+        let _ = write!(section, "\n*Part {part}*\n\n");
+        let solved = records.iter().find(|record| {
+            record.part == part && record.status == Status::Solved && record.answer.is_some()
+        });
+        let Some(answer) = solved.and_then(|record| record.answer.as_ref()) else {
+            section += "not solved yet\n";
+            continue;
+        };
+        let separator = if answer.is_multiline() { "\n" } else { " " };
+        let _ = write!(
+            section,
+            "```console\n\
+             $ adventofcode {day} {part} --year {year}\n\
+             day: {day}, part: {part}\n\
+             loading data from '{}'\n\
+             Duration: [..] us (parse [..] us, solve [..] us), peak memory [..]\n\
+             Solution:{separator}{answer}\n\
+             \n\
+             ```\n",
+            solved.map_or("", |record| record.input.as_str()),
+        );
+    }
+    section
+}
+
+/// replaces the day sections of the solutions section of the readme, everything up to the first
+/// day and everything after the section is kept
+pub fn replace_solutions(readme: &str, solutions: &str) -> Option<String> {
+    let (start, end) = solutions_span(readme)?;
+    let first_day = readme[start..end]
+        .find("\n### ")
+        .map_or(end, |offset| start + offset + 1);
+    Some(format!(
+        "{}{solutions}\n{}",
+        &readme[..first_day],
+        &readme[end..]
+    ))
+}
+
+/// adds a day section at the end of the solutions section of the readme
+pub fn add_day_section(readme: &str, section: &str) -> Option<String> {
+    let (_, end) = solutions_span(readme)?;
+    let before = readme[..end].trim_end_matches('\n');
+    let after = &readme[end..];
+    let separator = if after.is_empty() { "" } else { "\n" };
+    Some(format!("{before}\n\n{section}{separator}{after}"))
+}
+
+/// byte range of the solutions section, from the end of its heading to the next heading of the same
+/// level or the end of the readme
+fn solutions_span(readme: &str) -> Option<(usize, usize)> {
+    let heading = readme
+        .match_indices(SOLUTIONS_HEADING)
+        .find(|(idx, _)| *idx == 0 || readme[..*idx].ends_with('\n'))?
+        .0;
+    let start = heading + SOLUTIONS_HEADING.len();
+    let end = readme[start..]
+        .find("\n## ")
+        .map_or(readme.len(), |offset| start + offset + 1);
+    Some((start, end))
+}
+
+/// bar of `value` scaled so `max` fills `width` characters, using eighth blocks for the remainder
+fn bar(value: u128, max: u128, width: usize) -> String {
+    const EIGHTHS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+    if max == 0 {
+        return String::new();
+    }
+    let eighths = (value * width as u128 * 8 / max) as usize;
+    let mut bar = "█".repeat(eighths / 8);
+    if eighths % 8 != 0 {
+        bar.push(EIGHTHS[eighths % 8]);
+    }
+    bar
+}
+
+fn answer_or_summary(record: &Record) -> String {
+    match (&record.status, &record.answer) {
+        (Status::Solved, Some(answer)) => answer.to_string(),
+        _ => record.summary(),
+    }
+}
+fn display_or<T: std::fmt::Display>(value: Option<T>) -> String {
+    value.map_or(String::new(), |value| value.to_string())
+}
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::Dataset;
+    use crate::util::Answer;
+
+    fn solved(day: i32, part: i32, answer: i64, solve_us: u128) -> Record {
+        let input = format!("data/2023/day{day}/input{part}.txt");
+        let mut record = Record::new(day, part, Dataset::Real, input, Status::Solved);
+        record.answer = Some(Answer::from(answer));
+        record.solve_us = Some(solve_us);
+        record
+    }
+    #[test]
+    fn test_bar() {
+        assert_eq!(bar(100, 100, 4), "████");
+        assert_eq!(bar(25, 100, 4), "█");
+        assert_eq!(bar(30, 100, 4), "█▏");
+        assert_eq!(bar(0, 0, 4), "");
+    }
+    #[test]
+    fn test_day_section() {
+        let record = solved(1, 1, 142, 10);
+        assert_eq!(
+            day_section(2023, 1, &[&record]),
+            "### [Day 1](src/days/year2023/day1.rs)\n\n*Part 1*\n\n```console\n\
+             $ adventofcode 1 1 --year 2023\nday: 1, part: 1\nloading data from 'data/2023/day1/input1.txt'\n\
+             Duration: [..] us (parse [..] us, solve [..] us), peak memory [..]\nSolution: 142\n\n```\n\n\
+             *Part 2*\n\nnot solved yet\n"
+        );
+    }
+    #[test]
+    fn test_replace_solutions() {
+        let readme =
+            "# Title\n\n## Solutions\n\nall solutions\n\n### Day 1\n\nold\n\n## About\n\nme\n";
+        assert_eq!(
+            replace_solutions(readme, "### Day 1\n\nnew\n").unwrap(),
+            "# Title\n\n## Solutions\n\nall solutions\n\n### Day 1\n\nnew\n\n## About\n\nme\n"
+        );
+        assert_eq!(
+            add_day_section(readme, "### Day 2\n\nnew\n").unwrap(),
+            "# Title\n\n## Solutions\n\nall solutions\n\n### Day 1\n\nold\n\n### Day 2\n\nnew\n\n## About\n\nme\n"
+        );
+        assert_eq!(replace_solutions("# Title\n", ""), None);
+    }
+    #[test]
+    fn test_markdown() {
+        let records = [solved(1, 1, 142, 10), solved(1, 2, 281, 20)];
+        let report = Report {
+            year: 2023,
+            records: &records,
+        };
+        let markdown = report.markdown();
+        assert!(markdown.contains("| [1](src/days/year2023/day1.rs) | 2 | 281 |  | 20 |  |\n"));
+        assert!(markdown.contains(&format!("day  1 part 2 |{}|", "█".repeat(CHART_WIDTH))));
+        assert!(report
+            .html()
+            .contains("<a href=\"src/days/year2023/day1.rs\">1</a>"));
+    }
+}
@@ -0,0 +1,189 @@
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::timing;
+
+thread_local! {
+    /// cpu time of the guarded threads that finished, summed up per thread that waited for them
+    static GUARDED_CPU: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+}
+/// guarded threads that did not finish in time, they keep running until the process exits
+static ABANDONED: AtomicUsize = AtomicUsize::new(0);
+
+/// runs `function` on a thread of its own and waits at most `timeout` for its result
+///
+/// a thread can not be stopped, so one that does not finish in time is abandoned and counted by
+/// `abandoned`. the cpu time of the threads that finished is included in `cpu_time` of the calling
+/// thread, abandoned threads are left out of it
+pub fn guarded<T: Send + 'static>(
+    timeout: Duration,
+    function: impl FnOnce() -> T + Send + 'static,
+) -> Option<T> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let cpu0 = timing::thread_cpu_time();
+        let result = function();
+        let cpu = timing::thread_cpu_time()
+            .zip(cpu0)
+            .map(|(cpu, cpu0)| cpu - cpu0);
+        let _ = sender.send((result, cpu));
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok((result, cpu)) => {
+            GUARDED_CPU.with(|total| total.set(total.get() + cpu.unwrap_or_default()));
+            Some(result)
+        }
+        Err(_) => {
+            ABANDONED.fetch_add(1, Ordering::Relaxed);
+            None
+        }
+    }
+}
+
+/// number of guarded threads given up on so far
+pub fn abandoned() -> usize {
+    ABANDONED.load(Ordering::Relaxed)
+}
+
+/// cpu time consumed by the calling thread and the guarded threads it waited for
+pub fn cpu_time() -> Option<Duration> {
+    timing::thread_cpu_time().map(|cpu| cpu + GUARDED_CPU.with(Cell::get))
+}
+
+/// runs `run` for every item on `jobs` threads, handing each result to `done` as soon as the results
+/// of all items before it are done as well
+///
+/// returns the results in the order of `items` and the cpu time spent on them if it can be measured
+pub fn run_ordered<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    run: impl Fn(&T) -> R + Sync,
+    mut done: impl FnMut(&T, &R),
+) -> (Vec<R>, Option<Duration>) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1).min(items.len()) {
+            let sender = sender.clone();
+            let (next, run) = (&next, &run);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                let cpu0 = cpu_time();
+                let result = run(item);
+                let cpu = cpu_time().zip(cpu0).map(|(cpu, cpu0)| cpu - cpu0);
+                if sender.send((index, result, cpu)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut ordered = vec![];
+        let mut cpu = Some(Duration::ZERO);
+        for (index, result, item_cpu) in receiver {
+            finished.insert(index, result);
+            cpu = cpu.zip(item_cpu).map(|(cpu, item_cpu)| cpu + item_cpu);
+            while let Some(result) = finished.remove(&ordered.len()) {
+                done(&items[ordered.len()], &result);
+                ordered.push(result);
+            }
+        }
+        (ordered, cpu)
+    })
+}
+
+/// modification time and size of every watched file
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, (Option<SystemTime>, u64)>);
+
+impl Snapshot {
+    /// takes a snapshot of `paths`, directories are walked recursively and missing paths have no
+    /// files
+    pub fn take<P: AsRef<Path>>(paths: &[P]) -> Snapshot {
+        let mut snapshot = Snapshot::default();
+        for path in paths {
+            snapshot.add(path.as_ref());
+        }
+        snapshot
+    }
+
+    fn add(&mut self, path: &Path) {
+        let Ok(metadata) = std::fs::metadata(path) else {
+            return;
+        };
+        if !metadata.is_dir() {
+            self.0
+                .insert(path.to_owned(), (metadata.modified().ok(), metadata.len()));
+            return;
+        }
+        if let Ok(entries) = std::fs::read_dir(path) {
+            for entry in entries.flatten() {
+                self.add(&entry.path());
+            }
+        }
+    }
+
+    /// whether anything changed since `previous`, the first snapshot is always a change
+    pub fn changed(&self, previous: Option<&Snapshot>) -> bool {
+        previous != Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_guarded() {
+        assert_eq!(guarded(Duration::from_secs(10), || 42), Some(42));
+        let before = abandoned();
+        let slow = guarded(Duration::from_millis(10), || {
+            thread::sleep(Duration::from_millis(200));
+            42
+        });
+        assert_eq!(slow, None);
+        assert!(abandoned() > before);
+    }
+
+    #[test]
+    fn test_run_ordered() {
+        let items = [40, 30, 20, 10, 0];
+        let mut seen = vec![];
+        let (results, _) = run_ordered(
+            &items,
+            3,
+            |&millis| {
+                thread::sleep(Duration::from_millis(millis));
+                millis * 2
+            },
+            |&item, &result| seen.push((item, result)),
+        );
+        assert_eq!(results, [80, 60, 40, 20, 0]);
+        assert_eq!(seen, [(40, 80), (30, 60), (20, 40), (10, 20), (0, 0)]);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let dir = std::env::temp_dir().join(format!("adventofcode-watch-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("day1")).unwrap();
+        let input = dir.join("day1/input.txt");
+        std::fs::write(&input, "1\n").unwrap();
+
+        let first = Snapshot::take(&[&dir, &dir.join("missing.toml")]);
+        assert!(first.changed(None));
+        let second = Snapshot::take(&[&dir, &dir.join("missing.toml")]);
+        assert!(!second.changed(Some(&first)));
+        std::fs::write(&input, "1\n2\n").unwrap();
+        let third = Snapshot::take(&[&dir, &dir.join("missing.toml")]);
+        assert!(third.changed(Some(&second)));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
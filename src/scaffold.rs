@@ -0,0 +1,211 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use crate::days::DAYS;
+use crate::report;
+use crate::util;
+
+/// module of a new day, its parts are registered as not implemented until the stubs are filled in
+const TEMPLATE: &str = include_str!("days/_day.rs");
+
+/// error creating the files of a new day
+#[derive(Debug)]
+pub enum ScaffoldError {
+    UnknownDay(i32),
+    Exists(PathBuf),
+    Registry(PathBuf, String),
+    Io(PathBuf, std::io::Error),
+}
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::UnknownDay(day) => write!(f, "unknown day {day}"),
+            ScaffoldError::Exists(path) => write!(f, "'{}' already exists", path.display()),
+            ScaffoldError::Registry(path, message) => {
+                write!(f, "could not register in '{}': {message}", path.display())
+            }
+            ScaffoldError::Io(path, err) => {
+                write!(f, "could not access '{}': {err}", path.display())
+            }
+        }
+    }
+}
+impl std::error::Error for ScaffoldError {}
+
+/// creates the module of a new day below `root/src/days/year{year}` and registers it in the season
+/// module and the solver registry, creates the data folder of the day with empty inputs and adds a
+/// section to the readme
+///
+/// returns the paths of all files written, existing data files are left alone
+pub fn scaffold(
+    year: i32,
+    day: i32,
+    root: &Path,
+    data_path: &str,
+) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !DAYS.contains(&day) {
+        return Err(ScaffoldError::UnknownDay(day));
+    }
+    let days = root.join("src").join("days");
+    let season = days.join(format!("year{year}"));
+    let module = season.join(format!("day{day}.rs"));
+    if module.exists() {
+        return Err(ScaffoldError::Exists(module));
+    }
+    // everything is prepared before the first write, so a failure leaves the tree untouched
+    let registry = days.join("mod.rs");
+    let source = read(&registry)?;
+    let source = add_solvers(&source, year, day)
+        .map_err(|message| ScaffoldError::Registry(registry.clone(), message))?;
+    let source = add_module(&source, &format!("year{year}"));
+    let season_mod = season.join("mod.rs");
+    let season_source = match std::fs::read_to_string(&season_mod) {
+        Ok(source) => source,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(ScaffoldError::Io(season_mod, err)),
+    };
+    let season_source = add_module(&season_source, &format!("day{day}"));
+
+    let mut written = vec![];
+    create_dir(&season)?;
+    write(&module, TEMPLATE, &mut written)?;
+    write(&season_mod, &season_source, &mut written)?;
+    write(&registry, &source, &mut written)?;
+
+    let data = PathBuf::from(util::day_dir(year, day, data_path));
+    let examples = PathBuf::from(util::example_dir(year, day, data_path));
+    create_dir(&examples)?;
+    for path in [data.join("test_input1.txt"), examples.join("example.txt")] {
+        if !path.exists() {
+            write(&path, "", &mut written)?;
+        }
+    }
+
+    let readme = root.join("README.md");
+    if let Ok(text) = std::fs::read_to_string(&readme) {
+        let section = report::day_section(year, day, &[]);
+        let heading = section.lines().next().unwrap_or_default();
+        if !text.lines().any(|line| line == heading) {
+            let text = report::add_day_section(&text, &section)
+                .unwrap_or_else(|| format!("{}\n\n{section}", text.trim_end()));
+            write(&readme, &text, &mut written)?;
+        }
+    }
+    Ok(written)
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    std::fs::read_to_string(path).map_err(|err| ScaffoldError::Io(path.to_path_buf(), err))
+}
+fn write(path: &Path, content: &str, written: &mut Vec<PathBuf>) -> Result<(), ScaffoldError> {
+    std::fs::write(path, content).map_err(|err| ScaffoldError::Io(path.to_path_buf(), err))?;
+    written.push(path.to_path_buf());
+    Ok(())
+}
+fn create_dir(path: &Path) -> Result<(), ScaffoldError> {
+    std::fs::create_dir_all(path).map_err(|err| ScaffoldError::Io(path.to_path_buf(), err))
+}
+
+/// adds `pub mod {module};` to the module declarations of `source`, keeping them sorted like
+/// rustfmt does
+fn add_module(source: &str, module: &str) -> String {
+    let declaration = format!("pub mod {module};");
+    let lines: Vec<&str> = source.lines().collect();
+    let is_declaration = |line: &&str| line.starts_with("pub mod ") && line.ends_with(';');
+    let Some(first) = lines.iter().position(is_declaration) else {
+        let separator = if source.is_empty() { "" } else { "\n" };
+        return format!("{declaration}\n{separator}{source}");
+    };
+    let last = first
+        + lines[first..]
+            .iter()
+            .take_while(|line| is_declaration(line))
+            .count();
+    let mut declarations: Vec<&str> = lines[first..last].to_vec();
+    if !declarations.contains(&declaration.as_str()) {
+        declarations.push(&declaration);
+        declarations.sort_by_key(|line| line.trim_end_matches(';'));
+    }
+    let mut result: Vec<&str> = lines[..first].to_vec();
+    result.extend(declarations);
+    result.extend(&lines[last..]);
+    result.join("\n") + "\n"
+}
+
+/// adds both parts of a day to the `SOLVERS` registry in `source`, ordered by year and day
+fn add_solvers(source: &str, year: i32, day: i32) -> Result<String, String> {
+    let lines: Vec<&str> = source.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const SOLVERS"))
+        .ok_or("no SOLVERS registry found")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "];")
+            .ok_or("the SOLVERS registry is not closed")?;
+    let mut insert = end;
+    for (idx, line) in lines.iter().enumerate().take(end).skip(start + 1) {
+        let Some(entry) = line.trim().strip_prefix("solver!(") else {
+            continue;
+        };
+        let mut fields = entry.split(',').map(|field| field.trim().parse::<i32>());
+        let (Some(Ok(entry_year)), Some(Ok(entry_day))) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        if (entry_year, entry_day) == (year, day) {
+            return Err(format!("day {day} of {year} is already registered"));
+        }
+        if (entry_year, entry_day) > (year, day) && insert == end {
+            insert = idx;
+        }
+    }
+    let entries: Vec<String> = (1..=2)
+        .map(|part| {
+            format!(
+                "    solver!({year}, {day}, {part}, year{year}::day{day}::part{part}, implemented: false),"
+            )
+        })
+        .collect();
+    let mut result: Vec<&str> = lines[..insert].to_vec();
+    result.extend(entries.iter().map(String::as_str));
+    result.extend(&lines[insert..]);
+    Ok(result.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_add_module() {
+        let source = "pub mod day1;\npub mod day2;\n\nuse std::any::Any;\n";
+        assert_eq!(
+            add_module(source, "day10"),
+            "pub mod day1;\npub mod day10;\npub mod day2;\n\nuse std::any::Any;\n"
+        );
+        assert_eq!(
+            add_module("pub mod day12;\n", "day1"),
+            "pub mod day1;\npub mod day12;\n"
+        );
+        assert_eq!(add_module(source, "day2"), source);
+        assert_eq!(add_module("", "day3"), "pub mod day3;\n");
+    }
+    #[test]
+    fn test_add_solvers() {
+        let source = "pub const SOLVERS: &[Solver] = &[\n    solver!(2023, 1, 1, year2023::day1::part1),\n    solver!(2023, 3, 1, year2023::day3::part1),\n];\n";
+        let result = add_solvers(source, 2023, 2).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(
+            lines[2],
+            "    solver!(2023, 2, 1, year2023::day2::part1, implemented: false),"
+        );
+        assert_eq!(
+            lines[3],
+            "    solver!(2023, 2, 2, year2023::day2::part2, implemented: false),"
+        );
+        assert_eq!(lines[4], "    solver!(2023, 3, 1, year2023::day3::part1),");
+        let result = add_solvers(source, 2024, 1).unwrap();
+        assert!(result.ends_with("year2024::day1::part2, implemented: false),\n];\n"));
+        assert!(add_solvers(source, 2023, 3).is_err());
+    }
+}
@@ -0,0 +1,207 @@
+use std::fmt::Display;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use toml_edit::value;
+
+use crate::toml_file::{table_entry, FileError, TomlFile};
+
+/// summary of repeated runs, all durations in microseconds
+///
+/// serialized with the same field names as the csv columns
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Stats {
+    pub runs: usize,
+    #[serde(rename = "min_us")]
+    pub min: f64,
+    #[serde(rename = "median_us")]
+    pub median: f64,
+    #[serde(rename = "mean_us")]
+    pub mean: f64,
+    #[serde(rename = "p95_us")]
+    pub p95: f64,
+    #[serde(rename = "stddev_us")]
+    pub stddev: f64,
+}
+impl Stats {
+    /// computes the statistics of `samples`, returns `None` without samples
+    pub fn from_samples(samples: &[f64]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let runs = sorted.len();
+        let mean = sorted.iter().sum::<f64>() / runs as f64;
+        let variance = sorted.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / runs as f64;
+        Some(Self {
+            runs,
+            min: sorted[0],
+            median: percentile(&sorted, 50.),
+            mean,
+            p95: percentile(&sorted, 95.),
+            stddev: variance.sqrt(),
+        })
+    }
+}
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.1} us, median {:.1} us, mean {:.1} us, p95 {:.1} us, stddev {:.1} us",
+            self.min, self.median, self.mean, self.p95, self.stddev
+        )
+    }
+}
+
+/// nearest-rank percentile of already sorted samples
+fn percentile(sorted: &[f64], percent: f64) -> f64 {
+    let rank = (percent / 100. * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// calls `function` `warmup` times without measuring, then `runs` times measuring each call
+pub fn measure<T>(warmup: usize, runs: usize, mut function: impl FnMut() -> T) -> Option<Stats> {
+    for _ in 0..warmup {
+        black_box(function());
+    }
+    let samples: Vec<f64> = (0..runs)
+        .map(|_| {
+            let t0 = Instant::now();
+            black_box(function());
+            t0.elapsed().as_secs_f64() * 1e6
+        })
+        .collect();
+    Stats::from_samples(&samples)
+}
+
+/// cpu time consumed by the calling thread so far, `None` where it cannot be queried
+#[cfg(unix)]
+pub fn thread_cpu_time() -> Option<Duration> {
+    let mut time = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `time` is a valid timespec to write the result to
+    let result = unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut time) };
+    (result == 0).then(|| Duration::new(time.tv_sec as u64, time.tv_nsec as u32))
+}
+#[cfg(not(unix))]
+pub fn thread_cpu_time() -> Option<Duration> {
+    None
+}
+
+/// result of a timing run together with the comparison against the baseline
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Timing {
+    /// solving the already parsed puzzle, this is what the baseline is compared with
+    #[serde(flatten)]
+    pub stats: Stats,
+    /// parsing the input on its own
+    pub parse: Stats,
+    /// median of the baseline in microseconds
+    #[serde(rename = "baseline_us")]
+    pub baseline: Option<f64>,
+    pub regression: bool,
+}
+impl Timing {
+    /// a baseline of zero can not be compared against relatively, so it never flags a regression
+    pub fn new(stats: Stats, parse: Stats, baseline: Option<f64>, tolerance: f64) -> Self {
+        let regression = baseline.is_some_and(|baseline| {
+            baseline > 0. && stats.median > baseline * (1. + tolerance / 100.)
+        });
+        Self {
+            stats,
+            parse,
+            baseline,
+            regression,
+        }
+    }
+    /// relative change of the median against the baseline in percent
+    pub fn change(&self) -> Option<f64> {
+        self.baseline
+            .filter(|baseline| *baseline > 0.)
+            .map(|baseline| (self.stats.median / baseline - 1.) * 100.)
+    }
+}
+
+/// median durations of an earlier timing run, stored as toml keyed by day and part
+///
+/// ```toml
+/// [day1.part1]
+/// median_us = 51.274
+/// ```
+pub struct Baseline {
+    file: TomlFile,
+}
+impl Baseline {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, FileError> {
+        TomlFile::load(path).map(|file| Self { file })
+    }
+    pub fn path(&self) -> &Path {
+        self.file.path()
+    }
+    pub fn median(&self, day: i32, part: i32) -> Option<f64> {
+        let item = self
+            .file
+            .document()
+            .get(&format!("day{day}"))?
+            .get(format!("part{part}"))?
+            .get("median_us")?;
+        item.as_float()
+            .or_else(|| item.as_integer().map(|median| median as f64))
+    }
+    pub fn record(&mut self, day: i32, part: i32, stats: &Stats) {
+        let day = table_entry(self.file.document_mut(), &format!("day{day}"));
+        day.set_implicit(true);
+        let part = table_entry(day, &format!("part{part}"));
+        // nanosecond precision, so the median of a fast solve does not round to zero
+        part["median_us"] = value((stats.median * 1000.).round() / 1000.);
+    }
+    pub fn save(&self) -> Result<(), FileError> {
+        self.file.save()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_stats() {
+        let samples: Vec<f64> = (1..=20).map(f64::from).rev().collect();
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.runs, 20);
+        assert_eq!(stats.min, 1.);
+        assert_eq!(stats.median, 10.);
+        assert_eq!(stats.mean, 10.5);
+        assert_eq!(stats.p95, 19.);
+        assert!((stats.stddev - 5.766).abs() < 1e-3);
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+    #[test]
+    fn test_regression() {
+        let stats = Stats::from_samples(&[100.]).unwrap();
+        let parse = Stats::from_samples(&[1000.]).unwrap();
+        assert!(Timing::new(stats, parse, Some(80.), 10.).regression);
+        assert!(!Timing::new(stats, parse, Some(95.), 10.).regression);
+        assert!(!Timing::new(stats, parse, None, 10.).regression);
+        let zero = Timing::new(stats, parse, Some(0.), 10.);
+        assert!(!zero.regression);
+        assert_eq!(zero.change(), None);
+    }
+    #[test]
+    fn test_baseline_precision() {
+        let mut baseline = Baseline {
+            file: TomlFile::default(),
+        };
+        let stats = Stats::from_samples(&[0.042]).unwrap();
+        baseline.record(1, 1, &stats);
+        assert_eq!(baseline.median(1, 1), Some(0.042));
+        let parse = Stats::from_samples(&[1.]).unwrap();
+        let timing = Timing::new(stats, parse, baseline.median(1, 1), 10.);
+        assert!(!timing.regression);
+        assert_eq!(timing.change(), Some(0.));
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use toml_edit::{Document, Item, Table};
+
+/// error reading or writing one of the toml files kept next to the data
+#[derive(Debug)]
+pub enum FileError {
+    Io(PathBuf, std::io::Error),
+    Toml(PathBuf, toml_edit::TomlError),
+}
+impl Display for FileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FileError::Io(path, err) => {
+                write!(f, "could not access '{}': {err}", path.display())
+            }
+            FileError::Toml(path, err) => {
+                write!(f, "invalid toml file '{}': {err}", path.display())
+            }
+        }
+    }
+}
+impl std::error::Error for FileError {}
+
+/// toml document kept in one of the files next to the data
+///
+/// the document is edited in place, so comments and ordering of a hand-written file survive
+#[derive(Default)]
+pub struct TomlFile {
+    path: PathBuf,
+    document: Document,
+}
+impl TomlFile {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, FileError> {
+        let path = path.as_ref().to_path_buf();
+        let document = load_document(&path)?;
+        Ok(Self { path, document })
+    }
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    pub fn document(&self) -> &Document {
+        &self.document
+    }
+    pub fn document_mut(&mut self) -> &mut Table {
+        self.document.as_table_mut()
+    }
+    pub fn save(&self) -> Result<(), FileError> {
+        std::fs::write(&self.path, self.document.to_string())
+            .map_err(|err| FileError::Io(self.path.clone(), err))
+    }
+}
+
+/// reads a toml document, a missing file is treated as empty
+fn load_document(path: &Path) -> Result<Document, FileError> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => text
+            .parse::<Document>()
+            .map_err(|err| FileError::Toml(path.to_path_buf(), err)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Document::new()),
+        Err(err) => Err(FileError::Io(path.to_path_buf(), err)),
+    }
+}
+
+/// table stored under `key`, replacing any other item found there
+pub(crate) fn table_entry<'a>(table: &'a mut Table, key: &str) -> &'a mut Table {
+    let item = table.entry(key).or_insert(Item::Table(Table::new()));
+    if !item.is_table() {
+        *item = Item::Table(Table::new());
+    }
+    item.as_table_mut().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_toml_file() {
+        let mut file = TomlFile::load("does/not/exist.toml").unwrap();
+        assert_eq!(file.path(), Path::new("does/not/exist.toml"));
+        assert!(file.document().is_empty());
+        file.document_mut()["day1"] = toml_edit::value(1);
+        table_entry(file.document_mut(), "day1")["part1"] = toml_edit::value(2);
+        assert_eq!(file.document().to_string(), "[day1]\npart1 = 2\n");
+    }
+}
@@ -1,21 +1,324 @@
 use nalgebra::{DMatrix, DVector, Vector3};
 use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Write};
+use std::iter::{Skip, StepBy};
 use std::ops::{Add, Mul};
 use std::ops::{Index, IndexMut};
-pub fn parse_vec3(string: &str) -> Vector3<i64> {
-    Vector3::from_iterator(
-        string
-            .trim()
-            .split(',')
-            .filter_map(|part| part.trim().parse::<i64>().ok()),
-    )
-}
-pub fn load_file(day: i32, part: i32, runtest: bool, data_path: &str) -> String {
+use std::str::FromStr;
+
+pub mod search;
+
+/// line and column of a position in the puzzle input, both starting at 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// error for malformed puzzle input
+///
+/// the error is created from the offending fragment of the input, since the parsers mostly work on
+/// slices of the input the fragment's address is remembered and turned into a `Location` by `locate`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub location: Option<Location>,
+    address: Option<usize>,
+}
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            location: None,
+            address: None,
+        }
+    }
+    /// error pointing at the start of `fragment`
+    pub fn at(fragment: &str, message: impl Into<String>) -> Self {
+        Self {
+            address: Some(fragment.as_ptr() as usize),
+            ..Self::new(message)
+        }
+    }
+    /// error for a whole line of the input, starting at 1, used when the input is already parsed
+    pub fn on_line(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            location: Some(Location { line, column: 1 }),
+            ..Self::new(message)
+        }
+    }
+    /// error pointing right behind the end of `text`, used for truncated lines
+    pub fn at_end(text: &str, message: impl Into<String>) -> Self {
+        Self::at(&text[text.len()..], message)
+    }
+    /// resolves the line and column within `input`, fragments outside of `input` stay unlocated
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if let Some(offset) = self
+            .address
+            .and_then(|address| address.checked_sub(start))
+            .filter(|offset| *offset <= input.len())
+        {
+            let before = &input[..offset];
+            let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+            self.location = Some(Location {
+                line: before.matches('\n').count() + 1,
+                column: before[line_start..].chars().count() + 1,
+            });
+        }
+        self
+    }
+}
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.location {
+            Some(Location { line, column }) => {
+                write!(f, "line {line}, column {column}: {}", self.message)
+            }
+            None => f.write_str(&self.message),
+        }
+    }
+}
+impl std::error::Error for ParseError {}
+
+/// error of a part that could not be solved
+///
+/// the puzzle is already parsed at that point, so only errors the part found in the input lines
+/// themselves carry a location
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// malformed input that only shows while solving, like a line without any digit
+    Input(ParseError),
+    /// the input is well-formed but the part found no solution for it
+    Unsolvable(String),
+}
+impl SolveError {
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        Self::Unsolvable(message.into())
+    }
+}
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> Self {
+        Self::Input(err)
+    }
+}
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Input(err) => err.fmt(f),
+            SolveError::Unsolvable(message) => f.write_str(message),
+        }
+    }
+}
+impl std::error::Error for SolveError {}
+
+/// answer of a solver, most puzzles ask for a number but some want a text or a rendered grid
+///
+/// unsigned values are only stored as `Unsigned` if they do not fit into an `i64`, so equal
+/// numbers always compare equal regardless of the type the solver produced
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    Unsigned(u128),
+    Text(String),
+    Grid(Vec<String>),
+}
+impl Answer {
+    /// grid answers span several lines and are printed below the label instead of next to it
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Grid(rows) if rows.len() > 1)
+    }
+}
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Grid(rows) => f.write_str(&rows.join("\n")),
+        }
+    }
+}
+/// numbers stay numbers in machine-readable output, everything else is written as text
+impl serde::Serialize for Answer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Integer(value) => serializer.serialize_i64(*value),
+            Answer::Unsigned(value) => serializer.serialize_u128(*value),
+            answer => serializer.collect_str(answer),
+        }
+    }
+}
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim_end_matches('\n');
+        Ok(if let Ok(value) = text.parse::<i64>() {
+            Answer::Integer(value)
+        } else if let Ok(value) = text.parse::<u128>() {
+            Answer::Unsigned(value)
+        } else if text.contains('\n') {
+            Answer::Grid(text.lines().map(str::to_owned).collect())
+        } else {
+            Answer::Text(text.to_owned())
+        })
+    }
+}
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Integer(value)
+    }
+}
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Integer(value.into())
+    }
+}
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        i64::try_from(value).map_or(Answer::Unsigned(value), Answer::Integer)
+    }
+}
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::from(value as u128)
+    }
+}
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::from(value as u128)
+    }
+}
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
+
+pub fn parse_num<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse::<T>()
+        .map_err(|_| ParseError::at(text, format!("invalid number '{text}'")))
+}
+pub fn split_once<'a>(text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(text, format!("expected '{delimiter}' in '{text}'")))
+}
+/// takes the next token from `tokens`, reporting a missing token at the end of `line`
+pub fn next_token<'a>(
+    tokens: &mut impl Iterator<Item = &'a str>,
+    line: &'a str,
+    name: &str,
+) -> Result<&'a str, ParseError> {
+    tokens
+        .next()
+        .ok_or_else(|| ParseError::at_end(line, format!("missing {name}")))
+}
+pub fn parse_vec3(string: &str) -> Result<Vector3<i64>, ParseError> {
+    let values = string
+        .split(',')
+        .map(|part| parse_num::<i64>(part.trim()))
+        .collect::<Result<Vec<i64>, ParseError>>()?;
+    if values.len() != 3 {
+        return Err(ParseError::at(
+            string.trim_start(),
+            format!("expected 3 coordinates, found {}", values.len()),
+        ));
+    }
+    Ok(Vector3::from_vec(values))
+}
+/// folder holding the data of a day, seasons are kept apart as `{data_path}/{year}/day{day}`
+pub fn day_dir(year: i32, day: i32, data_path: &str) -> String {
+    std::format!("{data_path}/{year}/day{day}")
+}
+pub fn input_path(year: i32, day: i32, part: i32, runtest: bool, data_path: &str) -> String {
     let teststr = if runtest { "test_" } else { "" };
 
-    let path = std::format!("{data_path}/day{day}/{teststr}input{part}.txt");
-    println!("loading data from '{}'", path);
-    std::fs::read_to_string(path).unwrap()
+    std::format!("{}/{teststr}input{part}.txt", day_dir(year, day, data_path))
+}
+/// paths the input of a day and part is looked up at, both parts often share the same input so the
+/// second part falls back to the input of the first one
+pub fn input_paths(year: i32, day: i32, part: i32, runtest: bool, data_path: &str) -> Vec<String> {
+    let mut paths = vec![input_path(year, day, part, runtest, data_path)];
+    if part > 1 {
+        paths.push(input_path(year, day, 1, runtest, data_path));
+    }
+    paths
+}
+
+/// folder holding the named examples of a day, one `{name}.txt` file per example
+pub fn example_dir(year: i32, day: i32, data_path: &str) -> String {
+    std::format!("{}/examples", day_dir(year, day, data_path))
+}
+pub fn example_path(year: i32, day: i32, name: &str, data_path: &str) -> String {
+    std::format!("{}/{name}.txt", example_dir(year, day, data_path))
+}
+/// names of all examples of a day in alphabetical order
+pub fn example_names(year: i32, day: i32, data_path: &str) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(example_dir(year, day, data_path)) else {
+        return vec![];
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_owned())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// error for an input that could not be read from any of the paths tried
+#[derive(Debug)]
+pub struct InputError {
+    pub day: i32,
+    pub part: i32,
+    pub tried: Vec<(String, std::io::Error)>,
+}
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "no input for day {} part {}, tried ",
+            self.day, self.part
+        )?;
+        for (idx, (path, err)) in self.tried.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "'{path}'")?;
+            if err.kind() != std::io::ErrorKind::NotFound {
+                write!(f, " ({err})")?;
+            }
+        }
+        Ok(())
+    }
+}
+impl std::error::Error for InputError {}
+
+/// reads the first existing input of `input_paths`, returning its path and content
+pub fn read_input(
+    year: i32,
+    day: i32,
+    part: i32,
+    runtest: bool,
+    data_path: &str,
+) -> Result<(String, String), InputError> {
+    let mut tried = vec![];
+    for path in input_paths(year, day, part, runtest, data_path) {
+        match std::fs::read_to_string(&path) {
+            Ok(input) => return Ok((path, input)),
+            Err(err) => tried.push((path, err)),
+        }
+    }
+    Err(InputError { day, part, tried })
 }
 
 struct SearchState {
@@ -67,6 +370,16 @@ where
             assert_eq!(old, distance);
         }
     }
+    /// index of a node, nodes are numbered in the order they were added
+    pub fn index(&self, node: &T) -> Option<usize> {
+        self.nodes.get(node).copied()
+    }
+    /// indices of the nodes an edge leads to from the node with index `node` and their distances
+    pub fn neighbours(&self, node: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.edges[node]
+            .iter()
+            .map(|(next, distance)| (*next, *distance))
+    }
     pub fn find_longest_path(&self, start: &T, goal: &T) -> usize {
         let start = self.nodes[start];
         let goal = self.nodes[goal];
@@ -136,13 +449,13 @@ pub enum Direction {
     Left,
 }
 impl Direction {
-    pub fn from_char(value: char) -> Self {
+    pub fn from_char(value: char) -> Option<Self> {
         match value {
-            'U' => Direction::Up,
-            'R' => Direction::Right,
-            'D' => Direction::Down,
-            'L' => Direction::Left,
-            _ => panic!("should not happen!"),
+            'U' => Some(Direction::Up),
+            'R' => Some(Direction::Right),
+            'D' => Some(Direction::Down),
+            'L' => Some(Direction::Left),
+            _ => None,
         }
     }
     pub fn opposite(&self) -> Self {
@@ -169,6 +482,54 @@ pub struct MatrixIdx {
     pub col: i64,
 }
 
+/// the cells counted as neighbours of a cell, given as offsets to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood<'a> {
+    /// the cells sharing an edge, in the order of `ALL_DIRECTIONS`
+    Four,
+    /// the cells sharing an edge or a corner, row by row
+    Eight,
+    /// the six cells around a hexagon in axial coordinates, where the rows are the r and the columns
+    /// the q axis
+    Hex,
+    Stencil(&'a [MatrixIdx]),
+}
+impl<'a> Neighbourhood<'a> {
+    pub fn offsets(self) -> &'a [MatrixIdx] {
+        match self {
+            Neighbourhood::Four => &FOUR,
+            Neighbourhood::Eight => &EIGHT,
+            Neighbourhood::Hex => &HEX,
+            Neighbourhood::Stencil(offsets) => offsets,
+        }
+    }
+}
+
+const FOUR: [MatrixIdx; 4] = [
+    MatrixIdx { row: -1, col: 0 },
+    MatrixIdx { row: 0, col: 1 },
+    MatrixIdx { row: 1, col: 0 },
+    MatrixIdx { row: 0, col: -1 },
+];
+const EIGHT: [MatrixIdx; 8] = [
+    MatrixIdx { row: -1, col: -1 },
+    MatrixIdx { row: -1, col: 0 },
+    MatrixIdx { row: -1, col: 1 },
+    MatrixIdx { row: 0, col: -1 },
+    MatrixIdx { row: 0, col: 1 },
+    MatrixIdx { row: 1, col: -1 },
+    MatrixIdx { row: 1, col: 0 },
+    MatrixIdx { row: 1, col: 1 },
+];
+const HEX: [MatrixIdx; 6] = [
+    MatrixIdx { row: -1, col: 0 },
+    MatrixIdx { row: -1, col: 1 },
+    MatrixIdx { row: 0, col: 1 },
+    MatrixIdx { row: 1, col: 0 },
+    MatrixIdx { row: 1, col: -1 },
+    MatrixIdx { row: 0, col: -1 },
+];
+
 // fn get_rot(last: &Direction, next: &Direction) -> i64 {
 //     if last == next {
 //         return 0;
@@ -205,22 +566,35 @@ pub struct MatrixIdx {
 //     }
 // }
 
-pub trait FromChar {
-    fn from_char(char: &char) -> Self;
+pub trait FromChar: Sized {
+    fn from_char(char: &char) -> Option<Self>;
     fn default() -> Self;
 }
 pub trait MatrixElement: FromChar + Clone + PartialEq {}
 
 impl FromChar for i64 {
-    fn from_char(char: &char) -> Self {
-        *char as i64 - '0' as i64
+    fn from_char(char: &char) -> Option<Self> {
+        char.to_digit(10).map(|digit| digit as i64)
     }
     fn default() -> Self {
         0
     }
 }
 impl MatrixElement for i64 {}
-#[derive(Debug)]
+
+/// counterpart of `FromChar`, the character an element is rendered as
+pub trait ToChar {
+    fn to_char(&self) -> char;
+}
+impl ToChar for i64 {
+    fn to_char(&self) -> char {
+        u32::try_from(*self)
+            .ok()
+            .and_then(|digit| char::from_digit(digit, 10))
+            .unwrap_or('?')
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Matrix<T>
 where
     T: MatrixElement,
@@ -292,16 +666,29 @@ where
     fn linidx(&self, idx: &MatrixIdx) -> usize {
         (idx.row * self.width + idx.col) as usize
     }
-    pub fn from_string(input: &str) -> Self {
+    pub fn from_string(input: &str) -> Result<Self, ParseError> {
         let mut data = Vec::new();
         let mut width = 0;
-        for line in input.lines() {
-            width = line.len() as i64;
-            for c in line.chars() {
-                data.push(T::from_char(&c));
+        for (row, line) in input.lines().enumerate() {
+            if row == 0 {
+                width = line.len() as i64;
+            } else if line.len() as i64 != width {
+                return Err(ParseError::at(
+                    line,
+                    format!("expected a row of width {width}, found {}", line.len()),
+                ));
             }
+            for (col, c) in line.char_indices() {
+                let elem = T::from_char(&c).ok_or_else(|| {
+                    ParseError::at(&line[col..], format!("invalid character '{c}'"))
+                })?;
+                data.push(elem);
+            }
+        }
+        if width == 0 {
+            return Err(ParseError::at(input, "empty matrix"));
         }
-        Self { data, width }
+        Ok(Self { data, width })
     }
     fn idx_from_lin(&self, linidx: usize) -> MatrixIdx {
         MatrixIdx {
@@ -338,29 +725,88 @@ where
             .collect()
     }
     pub fn neighbour_idzs(&self, idx: &MatrixIdx) -> Vec<MatrixIdx> {
-        ALL_DIRECTIONS
+        self.neighbours(idx, Neighbourhood::Four).collect()
+    }
+    /// the neighbours of `idx` that lie inside the matrix
+    pub fn neighbours<'a>(
+        &'a self,
+        idx: &MatrixIdx,
+        neighbourhood: Neighbourhood<'a>,
+    ) -> impl Iterator<Item = MatrixIdx> + 'a {
+        self.neighbours_wrapped(idx, neighbourhood)
+            .filter(|next| self.contains(next))
+    }
+    /// the neighbours of `idx` on the plane tiled with the matrix, to be read with `get_wrapped`
+    pub fn neighbours_wrapped<'a>(
+        &self,
+        idx: &MatrixIdx,
+        neighbourhood: Neighbourhood<'a>,
+    ) -> impl Iterator<Item = MatrixIdx> + 'a {
+        let idx = *idx;
+        neighbourhood
+            .offsets()
             .iter()
-            .filter_map(|dir| self.next(idx, dir))
-            .collect()
+            .map(move |offset| idx + offset)
+    }
+    /// matrix of the given shape with every element set to `value`
+    pub fn filled(height: i64, width: i64, value: T) -> Self {
+        assert!(width > 0, "a matrix needs at least one column");
+        Self {
+            data: vec![value; (height * width) as usize],
+            width,
+        }
+    }
+    pub fn contains(&self, idx: &MatrixIdx) -> bool {
+        (0..self.height()).contains(&idx.row) && (0..self.width).contains(&idx.col)
+    }
+    pub fn get(&self, idx: &MatrixIdx) -> Option<&T> {
+        self.contains(idx).then(|| &self[*idx])
+    }
+    /// the elements of a row, borrowed from the matrix
+    pub fn row(&self, row: i64) -> Option<&[T]> {
+        (0..self.height()).contains(&row).then(|| {
+            let start = (row * self.width) as usize;
+            &self.data[start..start + self.width as usize]
+        })
+    }
+    pub fn row_mut(&mut self, row: i64) -> Option<&mut [T]> {
+        if !(0..self.height()).contains(&row) {
+            return None;
+        }
+        let start = (row * self.width) as usize;
+        Some(&mut self.data[start..start + self.width as usize])
+    }
+    pub fn rows(&self) -> std::slice::ChunksExact<'_, T> {
+        self.data.chunks_exact(self.width as usize)
+    }
+    /// the elements of a column, stepping through the data without copying
+    pub fn col(&self, col: i64) -> Option<Column<'_, T>> {
+        (0..self.width).contains(&col).then(|| {
+            self.data
+                .iter()
+                .skip(col as usize)
+                .step_by(self.width as usize)
+        })
+    }
+    pub fn cols(&self) -> impl DoubleEndedIterator<Item = Column<'_, T>> + '_ {
+        (0..self.width).filter_map(|col| self.col(col))
+    }
+    /// the region of `height` rows and `width` columns starting at `top_left`, none if it does not
+    /// fit into the matrix
+    pub fn view(&self, top_left: MatrixIdx, height: i64, width: i64) -> Option<MatrixView<'_, T>> {
+        let fits = top_left.row >= 0
+            && top_left.col >= 0
+            && height >= 0
+            && width >= 0
+            && top_left.row + height <= self.height()
+            && top_left.col + width <= self.width;
+        fits.then_some(MatrixView {
+            matrix: self,
+            top_left,
+            height,
+            width,
+        })
     }
-    // fn empty(nrows: usize, ncols: usize) -> Self {
-    //     Self {
-    //         data: vec![T::default(); nrows * ncols],
-    //         width: ncols as i64,
-    //     }
-    // }
-    // fn row(&self, idx: i64) -> Option<Vec<T>> {
-    //     if idx >= self.height() {
-    //         None
-    //     } else {
-    //         let start = idx * self.width;
-    //         let stop = (idx + 1) * self.width;
-    //         Some(self.data[start as usize..stop as usize].to_owned())
-    //     }
-    // }
-    // // fn rows(&self) -> FilterMap<std::ops::Range<i64>,Vec<T>> {
-    // //     (0..self.height()).filter_map(|idx| self.row(idx))
-    // // }
     pub fn height(&self) -> i64 {
         self.data.len() as i64 / self.width
     }
@@ -426,14 +872,455 @@ where
             .filter_map(|(idx, p)| condition(p).then_some(self.idx_from_lin(idx)))
             .collect()
     }
+    /// renders the matrix with markers drawn over some of its cells
+    pub fn overlay(&self) -> Overlay<'_, T> {
+        Overlay {
+            matrix: self,
+            markers: HashMap::new(),
+        }
+    }
+    /// the matrix seen through `transform`, elements are looked up in this matrix on access
+    pub fn transformed_view(&self, transform: Transform) -> TransformedView<'_, T> {
+        TransformedView {
+            matrix: self,
+            transform,
+        }
+    }
+    /// copy of the matrix with `transform` applied
+    pub fn transformed(&self, transform: Transform) -> Self {
+        self.transformed_view(transform).to_matrix()
+    }
+    pub fn transposed(&self) -> Self {
+        self.transformed(Transform::Transpose)
+    }
+    pub fn rotated_cw(&self) -> Self {
+        self.transformed(Transform::RotateCw)
+    }
+    pub fn rotated_ccw(&self) -> Self {
+        self.transformed(Transform::RotateCcw)
+    }
+    pub fn flipped_horizontal(&self) -> Self {
+        self.transformed(Transform::FlipHorizontal)
+    }
+    pub fn flipped_vertical(&self) -> Self {
+        self.transformed(Transform::FlipVertical)
+    }
+    /// transposes the matrix, square matrices are transposed without allocating
+    pub fn transpose(&mut self) {
+        if self.height() != self.width {
+            *self = self.transposed();
+            return;
+        }
+        let n = self.width as usize;
+        for row in 0..n {
+            for col in row + 1..n {
+                self.data.swap(row * n + col, col * n + row);
+            }
+        }
+    }
+    pub fn rotate_cw(&mut self) {
+        self.transpose();
+        self.flip_horizontal();
+    }
+    pub fn rotate_ccw(&mut self) {
+        self.transpose();
+        self.flip_vertical();
+    }
+    /// mirrors the matrix at its vertical axis, the columns are reversed
+    pub fn flip_horizontal(&mut self) {
+        let width = self.width as usize;
+        for row in self.data.chunks_exact_mut(width) {
+            row.reverse();
+        }
+    }
+    /// mirrors the matrix at its horizontal axis, the rows are reversed
+    pub fn flip_vertical(&mut self) {
+        let width = self.width as usize;
+        let height = self.height() as usize;
+        for row in 0..height / 2 {
+            let (top, bottom) = self.data.split_at_mut((height - 1 - row) * width);
+            top[row * width..(row + 1) * width].swap_with_slice(&mut bottom[..width]);
+        }
+    }
 }
 
-// fn print_matrix(matrix: &Matrix<i64>) {
-//     for i in 0..matrix.height() {
-//         let line = matrix.row(i).unwrap();
-//         for char in line {
-//             print!("{}", char);
-//         }
-//         println!();
-//     }
-// }
+/// the elements of a column of a matrix, from top to bottom
+pub type Column<'a, T> = StepBy<Skip<std::slice::Iter<'a, T>>>;
+
+/// rectangular region of a matrix, indexed relative to its top left corner
+#[derive(Debug, Clone)]
+pub struct MatrixView<'a, T>
+where
+    T: MatrixElement,
+{
+    matrix: &'a Matrix<T>,
+    top_left: MatrixIdx,
+    height: i64,
+    width: i64,
+}
+impl<'a, T> MatrixView<'a, T>
+where
+    T: MatrixElement,
+{
+    pub fn height(&self) -> i64 {
+        self.height
+    }
+    pub fn width(&self) -> i64 {
+        self.width
+    }
+    pub fn contains(&self, idx: &MatrixIdx) -> bool {
+        (0..self.height).contains(&idx.row) && (0..self.width).contains(&idx.col)
+    }
+    pub fn get(&self, idx: &MatrixIdx) -> Option<&'a T> {
+        self.contains(idx)
+            .then(|| &self.matrix[self.top_left + idx])
+    }
+    pub fn row(&self, row: i64) -> Option<&'a [T]> {
+        if !(0..self.height).contains(&row) {
+            return None;
+        }
+        let row = self.matrix.row(self.top_left.row + row)?;
+        let start = self.top_left.col as usize;
+        Some(&row[start..start + self.width as usize])
+    }
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'a [T]> + '_ {
+        (0..self.height).filter_map(|row| self.row(row))
+    }
+    pub fn col(
+        &self,
+        col: i64,
+    ) -> Option<impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + 'a> {
+        if !(0..self.width).contains(&col) {
+            return None;
+        }
+        let column = self.matrix.col(self.top_left.col + col)?;
+        Some(
+            column
+                .skip(self.top_left.row as usize)
+                .take(self.height as usize),
+        )
+    }
+}
+impl<T> Index<MatrixIdx> for MatrixView<'_, T>
+where
+    T: MatrixElement,
+{
+    type Output = T;
+
+    fn index(&self, index: MatrixIdx) -> &Self::Output {
+        self.get(&index).expect("index outside of the view")
+    }
+}
+
+/// rotations and reflections of a matrix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transform {
+    Identity,
+    /// rows become columns
+    Transpose,
+    /// rotation by a quarter turn clockwise
+    RotateCw,
+    /// rotation by a quarter turn counterclockwise
+    RotateCcw,
+    Rotate180,
+    /// mirror at the vertical axis, left and right are swapped
+    FlipHorizontal,
+    /// mirror at the horizontal axis, top and bottom are swapped
+    FlipVertical,
+}
+impl Transform {
+    fn swaps_axes(&self) -> bool {
+        matches!(
+            self,
+            Transform::Transpose | Transform::RotateCw | Transform::RotateCcw
+        )
+    }
+    /// height and width of a matrix of the given shape after the transform
+    pub fn shape(&self, height: i64, width: i64) -> (i64, i64) {
+        if self.swaps_axes() {
+            (width, height)
+        } else {
+            (height, width)
+        }
+    }
+    /// index in a matrix of the given shape of the element that ends up at `idx` after the transform
+    pub fn source(&self, idx: &MatrixIdx, height: i64, width: i64) -> MatrixIdx {
+        let MatrixIdx { row, col } = *idx;
+        let (row, col) = match self {
+            Transform::Identity => (row, col),
+            Transform::Transpose => (col, row),
+            Transform::RotateCw => (height - 1 - col, row),
+            Transform::RotateCcw => (col, width - 1 - row),
+            Transform::Rotate180 => (height - 1 - row, width - 1 - col),
+            Transform::FlipHorizontal => (row, width - 1 - col),
+            Transform::FlipVertical => (height - 1 - row, col),
+        };
+        MatrixIdx { row, col }
+    }
+}
+
+/// a matrix seen through a transform, indexed in the transformed orientation
+#[derive(Debug, Clone)]
+pub struct TransformedView<'a, T>
+where
+    T: MatrixElement,
+{
+    matrix: &'a Matrix<T>,
+    transform: Transform,
+}
+impl<'a, T> TransformedView<'a, T>
+where
+    T: MatrixElement,
+{
+    pub fn height(&self) -> i64 {
+        self.shape().0
+    }
+    pub fn width(&self) -> i64 {
+        self.shape().1
+    }
+    fn shape(&self) -> (i64, i64) {
+        self.transform
+            .shape(self.matrix.height(), self.matrix.width())
+    }
+    /// index in the underlying matrix of the element at `idx`
+    pub fn source(&self, idx: &MatrixIdx) -> MatrixIdx {
+        self.transform
+            .source(idx, self.matrix.height(), self.matrix.width())
+    }
+    pub fn contains(&self, idx: &MatrixIdx) -> bool {
+        (0..self.height()).contains(&idx.row) && (0..self.width()).contains(&idx.col)
+    }
+    pub fn get(&self, idx: &MatrixIdx) -> Option<&'a T> {
+        self.contains(idx).then(|| &self.matrix[self.source(idx)])
+    }
+    pub fn row(&self, row: i64) -> Option<impl DoubleEndedIterator<Item = &'a T> + '_> {
+        (0..self.height()).contains(&row).then(|| {
+            (0..self.width()).map(move |col| &self.matrix[self.source(&MatrixIdx { row, col })])
+        })
+    }
+    pub fn col(&self, col: i64) -> Option<impl DoubleEndedIterator<Item = &'a T> + '_> {
+        (0..self.width()).contains(&col).then(|| {
+            (0..self.height()).map(move |row| &self.matrix[self.source(&MatrixIdx { row, col })])
+        })
+    }
+    /// copies the elements into a new matrix in the transformed orientation
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let (height, width) = self.shape();
+        let data = (0..height)
+            .flat_map(|row| (0..width).map(move |col| MatrixIdx { row, col }))
+            .map(|idx| self.matrix[self.source(&idx)].clone())
+            .collect();
+        Matrix { data, width }
+    }
+}
+impl<T> Index<MatrixIdx> for TransformedView<'_, T>
+where
+    T: MatrixElement,
+{
+    type Output = T;
+
+    fn index(&self, index: MatrixIdx) -> &Self::Output {
+        self.get(&index).expect("index outside of the view")
+    }
+}
+
+/// one line per row, the output parses back into the same matrix with `Matrix::from_string`
+impl<T> Display for Matrix<T>
+where
+    T: MatrixElement + ToChar,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.overlay().fmt(f)
+    }
+}
+
+/// a matrix rendered with markers replacing some of its cells, e.g. a path or the visited cells
+///
+/// ```ignore
+/// log::debug!("{}", matrix.overlay().mark(path, 'O').mark([start], 'S'));
+/// ```
+pub struct Overlay<'a, T>
+where
+    T: MatrixElement,
+{
+    matrix: &'a Matrix<T>,
+    markers: HashMap<MatrixIdx, char>,
+}
+impl<T> Overlay<'_, T>
+where
+    T: MatrixElement,
+{
+    /// renders `cells` as `marker`, later markers are drawn over earlier ones
+    pub fn mark(mut self, cells: impl IntoIterator<Item = MatrixIdx>, marker: char) -> Self {
+        self.markers
+            .extend(cells.into_iter().map(|cell| (cell, marker)));
+        self
+    }
+}
+impl<T> Display for Overlay<'_, T>
+where
+    T: MatrixElement + ToChar,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (row, line) in self.matrix.rows().enumerate() {
+            if row > 0 {
+                f.write_char('\n')?;
+            }
+            for (col, element) in line.iter().enumerate() {
+                let idx = MatrixIdx {
+                    row: row as i64,
+                    col: col as i64,
+                };
+                f.write_char(
+                    self.markers
+                        .get(&idx)
+                        .copied()
+                        .unwrap_or_else(|| element.to_char()),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_parse_error_locate() {
+        let input = "12 ab\n34 cd\nef";
+        let line = input.lines().nth(1).unwrap();
+        let err = ParseError::at(&line[3..], "expected a number").locate(input);
+        assert_eq!(err.location, Some(Location { line: 2, column: 4 }));
+        assert_eq!(err.to_string(), "line 2, column 4: expected a number");
+
+        // the last line has no trailing newline
+        let last = input.lines().last().unwrap();
+        let err = ParseError::at(&last[1..], "invalid").locate(input);
+        assert_eq!(err.location, Some(Location { line: 3, column: 2 }));
+        let err = ParseError::at_end(last, "truncated").locate(input);
+        assert_eq!(err.location, Some(Location { line: 3, column: 3 }));
+        let err = ParseError::at_end(input.lines().next().unwrap(), "truncated").locate(input);
+        assert_eq!(err.location, Some(Location { line: 1, column: 6 }));
+
+        // fragments of another string stay unlocated
+        let other = String::from("12 ab");
+        let err = ParseError::at(&other[3..], "invalid").locate(input);
+        assert_eq!(err.location, None);
+        assert_eq!(err.to_string(), "invalid");
+
+        let err = ParseError::on_line(7, "wrong count").locate(input);
+        assert_eq!(err.location, Some(Location { line: 7, column: 1 }));
+        assert_eq!(err.to_string(), "line 7, column 1: wrong count");
+    }
+    #[test]
+    fn test_answer_roundtrip() {
+        let answers = [
+            Answer::from(-42),
+            Answer::from(u128::MAX),
+            Answer::from("done!"),
+            Answer::Grid(vec!["#.".to_owned(), ".#".to_owned()]),
+        ];
+        for answer in answers {
+            assert_eq!(answer.to_string().parse::<Answer>(), Ok(answer));
+        }
+        assert_eq!(Answer::from(42usize), Answer::Integer(42));
+    }
+    #[test]
+    fn test_matrix_views() {
+        let matrix: Matrix<i64> = Matrix::from_string("123\n456\n789").unwrap();
+        assert_eq!(matrix.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(matrix.row(3), None);
+        assert_eq!(
+            matrix.col(2).unwrap().copied().collect::<Vec<_>>(),
+            [3, 6, 9]
+        );
+        assert_eq!(matrix.col(0).unwrap().next_back(), Some(&7));
+        assert_eq!(matrix.cols().count(), 3);
+        assert_eq!(matrix.get(&MatrixIdx { row: -1, col: 0 }), None);
+
+        let view = matrix.view(MatrixIdx { row: 1, col: 1 }, 2, 2).unwrap();
+        assert_eq!(view[MatrixIdx { row: 0, col: 0 }], 5);
+        assert_eq!(view.rows().collect::<Vec<_>>(), [&[5, 6][..], &[8, 9][..]]);
+        assert_eq!(view.col(1).unwrap().copied().collect::<Vec<_>>(), [6, 9]);
+        assert_eq!(view.get(&MatrixIdx { row: 2, col: 0 }), None);
+        assert!(matrix.view(MatrixIdx { row: 2, col: 0 }, 2, 1).is_none());
+
+        let mut filled = Matrix::filled(2, 3, 0);
+        filled.row_mut(1).unwrap()[2] = 1;
+        assert_eq!(filled.rows().last(), Some(&[0, 0, 1][..]));
+        assert_eq!(filled, Matrix::from_string("000\n001").unwrap());
+    }
+    #[test]
+    fn test_matrix_transforms() {
+        let matrix: Matrix<i64> = Matrix::from_string("123\n456").unwrap();
+        let expected = [
+            (Transform::Transpose, "14\n25\n36"),
+            (Transform::RotateCw, "41\n52\n63"),
+            (Transform::RotateCcw, "36\n25\n14"),
+            (Transform::Rotate180, "654\n321"),
+            (Transform::FlipHorizontal, "321\n654"),
+            (Transform::FlipVertical, "456\n123"),
+        ];
+        for (transform, expected) in expected {
+            assert_eq!(
+                matrix.transformed(transform),
+                Matrix::from_string(expected).unwrap(),
+                "{transform:?}"
+            );
+        }
+        let view = matrix.transformed_view(Transform::RotateCw);
+        assert_eq!((view.height(), view.width()), (3, 2));
+        assert_eq!(view[MatrixIdx { row: 0, col: 0 }], 4);
+        assert_eq!(view.col(1).unwrap().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+        let mut rotated = matrix.clone();
+        rotated.rotate_cw();
+        assert_eq!(rotated, matrix.rotated_cw());
+        rotated.rotate_ccw();
+        assert_eq!(rotated, matrix);
+        let mut square: Matrix<i64> = Matrix::from_string("12\n34").unwrap();
+        square.transpose();
+        assert_eq!(square, Matrix::from_string("13\n24").unwrap());
+        square.flip_vertical();
+        assert_eq!(square, Matrix::from_string("24\n13").unwrap());
+    }
+    #[test]
+    fn test_matrix_display() {
+        let matrix: Matrix<i64> = Matrix::from_string("123\n456").unwrap();
+        assert_eq!(matrix.to_string(), "123\n456");
+        assert_eq!(Matrix::from_string(&matrix.to_string()), Ok(matrix.clone()));
+        let path = [MatrixIdx { row: 0, col: 0 }, MatrixIdx { row: 1, col: 0 }];
+        assert_eq!(
+            matrix
+                .overlay()
+                .mark(path, 'O')
+                .mark([MatrixIdx { row: 1, col: 0 }], 'E')
+                .to_string(),
+            "O23\nE56"
+        );
+    }
+    #[test]
+    fn test_neighbours() {
+        let matrix: Matrix<i64> = Matrix::from_string("123\n456\n789").unwrap();
+        let values = |neighbourhood| {
+            matrix
+                .neighbours(&MatrixIdx { row: 0, col: 1 }, neighbourhood)
+                .map(|idx| matrix[idx])
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values(Neighbourhood::Four), [3, 5, 1]);
+        assert_eq!(values(Neighbourhood::Eight), [1, 3, 4, 5, 6]);
+        assert_eq!(values(Neighbourhood::Hex), [3, 5, 4, 1]);
+        let knight = [MatrixIdx { row: 2, col: 1 }, MatrixIdx { row: 2, col: -1 }];
+        assert_eq!(values(Neighbourhood::Stencil(&knight)), [9, 7]);
+
+        let corner = MatrixIdx { row: 0, col: 0 };
+        let wrapped: Vec<i64> = matrix
+            .neighbours_wrapped(&corner, Neighbourhood::Four)
+            .map(|idx| *matrix.get_wrapped(&idx))
+            .collect();
+        assert_eq!(wrapped, [7, 2, 4, 3]);
+        assert_eq!(matrix.neighbour_idzs(&corner).len(), 2);
+    }
+}
@@ -0,0 +1,254 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// result of a search, the distances and predecessors of every state reached
+///
+/// when the search stopped at a goal, the distances of states that were reached but not expanded
+/// yet are upper bounds
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    pub distances: HashMap<S, usize>,
+    /// the state every reached state was reached from on its shortest path, starts have none
+    pub predecessors: HashMap<S, S>,
+    /// the first goal state expanded, none if the search ran out of states
+    pub goal: Option<S>,
+}
+impl<S> Search<S>
+where
+    S: Hash + Eq + Clone,
+{
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+    /// the states from a start to `state`, none if `state` was not reached
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        self.distances.get(state)?;
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors.get(path.last()?) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+    /// the states from a start to the goal
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.path(self.goal.as_ref()?)
+    }
+    /// records `next` as reached from `state` if `distance` improves on what was known
+    fn relax(&mut self, state: &S, next: &S, distance: usize) -> bool {
+        if self
+            .distances
+            .get(next)
+            .is_some_and(|known| *known <= distance)
+        {
+            return false;
+        }
+        self.distances.insert(next.clone(), distance);
+        self.predecessors.insert(next.clone(), state.clone());
+        true
+    }
+}
+
+/// breadth first search, every step costs one
+///
+/// expands the states reachable from `starts` in the order of their distance until a state
+/// fulfilling `goal` is expanded, pass `|_| false` to reach every state
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if search.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back((0, start));
+        }
+    }
+    while let Some((distance, state)) = queue.pop_front() {
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        for next in neighbours(&state) {
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), distance + 1);
+                search.predecessors.insert(next.clone(), state.clone());
+                queue.push_back((distance + 1, next));
+            }
+        }
+    }
+    search
+}
+
+/// shortest paths where `neighbours` returns the reachable states together with the cost of the step
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(starts, neighbours, |_| 0, goal)
+}
+
+/// dijkstra guided by `heuristic`, an estimate of the cost left to a goal that must never
+/// overestimate it and must not drop by more than the cost of a step
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut search = Search::new();
+    // the states are kept out of the heap, so they do not need to be ordered
+    let mut states = Vec::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if search.distances.insert(start.clone(), 0).is_none() {
+            queue.push(Reverse((heuristic(&start), 0, states.len())));
+            states.push(start);
+        }
+    }
+    while let Some(Reverse((_, distance, id))) = queue.pop() {
+        let state = states[id].clone();
+        if search.distances[&state] < distance {
+            // a shorter path to the state was found after it was queued
+            continue;
+        }
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        for (next, cost) in neighbours(&state) {
+            let next_distance = distance + cost;
+            if search.relax(&state, &next, next_distance) {
+                queue.push(Reverse((
+                    next_distance + heuristic(&next),
+                    next_distance,
+                    states.len(),
+                )));
+                states.push(next);
+            }
+        }
+    }
+    search
+}
+
+/// shortest paths where every step costs either zero or one, cheaper than dijkstra for such costs
+pub fn zero_one_bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if search.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back((0, start));
+        }
+    }
+    while let Some((distance, state)) = queue.pop_front() {
+        if search.distances[&state] < distance {
+            continue;
+        }
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        for (next, cost) in neighbours(&state) {
+            assert!(cost <= 1, "zero one bfs needs steps of cost 0 or 1");
+            if search.relax(&state, &next, distance + cost) {
+                if cost == 0 {
+                    queue.push_front((distance, next));
+                } else {
+                    queue.push_back((distance + 1, next));
+                }
+            }
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{Graph, Matrix, MatrixIdx};
+
+    #[test]
+    fn test_search() {
+        let matrix: Matrix<i64> = Matrix::from_string("131\n191\n111").unwrap();
+        let start = MatrixIdx { row: 0, col: 0 };
+        let end = MatrixIdx { row: 2, col: 2 };
+        let steps = |idx: &MatrixIdx| matrix.neighbour_idzs(idx);
+        let costs = |idx: &MatrixIdx| {
+            matrix
+                .neighbour_idzs(idx)
+                .into_iter()
+                .map(|next| (next, matrix[next] as usize))
+        };
+
+        let search = bfs([start], steps, |_| false);
+        assert_eq!(search.distance(&end), Some(4));
+        assert_eq!(search.distances.len(), 9);
+
+        let search = dijkstra([start], costs, |idx| idx == &end);
+        assert_eq!(search.goal, Some(end));
+        assert_eq!(search.distance(&end), Some(4));
+        let path = search.goal_path().unwrap();
+        assert_eq!(path.len(), 5);
+        assert_eq!(path[1], MatrixIdx { row: 1, col: 0 });
+
+        let manhattan = |idx: &MatrixIdx| ((end.row - idx.row) + (end.col - idx.col)) as usize;
+        let search = astar([start], costs, manhattan, |idx| idx == &end);
+        assert_eq!(search.distance(&end), Some(4));
+
+        // stepping on a 1 is free, everything else costs one
+        let search = zero_one_bfs(
+            [start],
+            |idx| {
+                matrix
+                    .neighbour_idzs(idx)
+                    .into_iter()
+                    .map(|next| (next, usize::from(matrix[next] != 1)))
+            },
+            |_| false,
+        );
+        assert_eq!(search.distance(&end), Some(0));
+        assert_eq!(search.distance(&MatrixIdx { row: 1, col: 1 }), Some(1));
+
+        let mut graph = Graph::new();
+        for node in ["a", "b", "c"] {
+            graph.add_node(node);
+        }
+        graph.add_edge(&"a", &"b", 5);
+        graph.add_edge(&"a", &"c", 1);
+        graph.add_edge(&"c", &"b", 2);
+        let (a, b) = (graph.index(&"a").unwrap(), graph.index(&"b").unwrap());
+        let search = dijkstra([a], |node| graph.neighbours(*node), |_| false);
+        assert_eq!(search.distance(&b), Some(3));
+        assert_eq!(search.path(&b).map(|path| path.len()), Some(3));
+    }
+}
@@ -0,0 +1,175 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use assert_cmd::Command;
+
+/// request line, headers and body of a request received by the stub server
+#[derive(Debug, Clone)]
+struct Request {
+    line: String,
+    headers: Vec<String>,
+    body: String,
+}
+
+/// serves every request with status 200 and `body`, returns its base url and the received requests
+fn stub_server(body: &'static str) -> (String, Arc<Mutex<Vec<Request>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(vec![]));
+    let received = requests.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let mut headers = vec![];
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                headers.push(header.trim().to_owned());
+            }
+            let length = headers
+                .iter()
+                .find_map(|header| {
+                    header
+                        .to_lowercase()
+                        .strip_prefix("content-length:")?
+                        .trim()
+                        .parse()
+                        .ok()
+                })
+                .unwrap_or(0);
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+            received.lock().unwrap().push(Request {
+                line: line.trim().to_owned(),
+                headers,
+                body: String::from_utf8(content).unwrap(),
+            });
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+    (url, requests)
+}
+
+fn data_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("adventofcode-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn test_fetch() {
+    let (url, requests) = stub_server("1abc2\npqr3stu8vwx\n");
+    let data = data_dir("fetch");
+    let fetch = || {
+        Command::cargo_bin("adventofcode")
+            .unwrap()
+            .args(["fetch", "1", "--year", "2023", "--data"])
+            .arg(&data)
+            .env("AOC_SESSION", "secret")
+            .env("AOC_BASE_URL", &url)
+            .assert()
+            .success()
+    };
+    fetch();
+    assert_eq!(
+        std::fs::read_to_string(data.join("2023/day1/input1.txt")).unwrap(),
+        "1abc2\npqr3stu8vwx\n"
+    );
+    // the cached input is not downloaded again
+    fetch();
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].line, "GET /2023/day/1/input HTTP/1.1");
+    assert!(requests[0]
+        .headers
+        .iter()
+        .any(|header| header == "Cookie: session=secret"));
+    assert!(requests[0].body.is_empty());
+    std::fs::remove_dir_all(&data).unwrap();
+}
+
+#[test]
+fn test_fetch_without_session() {
+    let data = data_dir("nosession");
+    Command::cargo_bin("adventofcode")
+        .unwrap()
+        .args(["fetch", "1", "--data"])
+        .arg(&data)
+        .env_remove("AOC_SESSION")
+        .env("AOC_BASE_URL", "http://127.0.0.1:9")
+        .assert()
+        .failure();
+    assert!(!data.join("2023/day1/input1.txt").exists());
+}
+
+/// data folder holding the calibration example of day 1 as real input
+fn calibration_data(name: &str, config: &str) -> PathBuf {
+    let data = data_dir(name);
+    std::fs::create_dir_all(data.join("2023/day1")).unwrap();
+    std::fs::write(
+        data.join("2023/day1/input1.txt"),
+        "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n",
+    )
+    .unwrap();
+    std::fs::write(data.join("config.toml"), config).unwrap();
+    data
+}
+
+fn submit(data: &PathBuf, url: &str, part: &str) -> assert_cmd::assert::Assert {
+    Command::cargo_bin("adventofcode")
+        .unwrap()
+        .args(["submit", "1", part, "--data"])
+        .arg(data)
+        .env("AOC_SESSION", "secret")
+        .env("AOC_BASE_URL", url)
+        .assert()
+}
+
+#[test]
+fn test_submit_rejected() {
+    let (url, requests) = stub_server(
+        "<main><article><p>That's not the right answer; your answer is too low.</p></article></main>",
+    );
+    let data = calibration_data("rejected", "cooldown = 0\n");
+    submit(&data, &url, "1")
+        .failure()
+        .stdout("Solution: 142\nThat's not the right answer; your answer is too low.\n");
+    let history = std::fs::read_to_string(data.join("2023/submissions.toml")).unwrap();
+    assert!(history.starts_with("[[day1.part1]]\nanswer = 142\nreply = \"wrong\"\n"));
+    // the rejected answer is not submitted again
+    submit(&data, &url, "1")
+        .failure()
+        .stderr("error: not submitting, 142 was already rejected as too low\n");
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].line, "POST /2023/day/1/answer HTTP/1.1");
+    assert_eq!(requests[0].body, "level=1&answer=142");
+    std::fs::remove_dir_all(&data).unwrap();
+}
+
+#[test]
+fn test_submit_cooldown() {
+    let (url, requests) = stub_server(
+        "<article><p>That's the right answer! You are one gold star closer.</p></article>",
+    );
+    let data = calibration_data("cooldown", "cooldown = 600\n");
+    submit(&data, &url, "1").success();
+    let refused = submit(&data, &url, "2").failure();
+    let stderr = String::from_utf8_lossy(&refused.get_output().stderr);
+    assert!(stderr.starts_with("error: not submitting, an answer was submitted recently"));
+    assert_eq!(requests.lock().unwrap().len(), 1);
+    std::fs::remove_dir_all(&data).unwrap();
+}